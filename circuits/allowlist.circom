@@ -0,0 +1,45 @@
+pragma circom 2.2.1;
+
+include "circomlib/circuits/poseidon.circom";
+
+// Membership of the signer in the allowlist of a pool: a Poseidon Merkle tree
+// whose leaves are Poseidon(user_hi, user_lo), the user pubkey split in its
+// high and low 128 bits. The path is private, so the proof does not reveal
+// which leaf is the user.
+//
+// Public signals: [allowlist_root, user_hi, user_lo].
+template Allowlist(depth) {
+    signal input allowlist_root;
+    signal input user_hi;
+    signal input user_lo;
+
+    // Siblings from the leaf up, path_indices[i] is 1 when the node is the
+    // right child at level i.
+    signal input path_elements[depth];
+    signal input path_indices[depth];
+
+    component leaf = Poseidon(2);
+    leaf.inputs[0] <== user_hi;
+    leaf.inputs[1] <== user_lo;
+
+    component levels[depth];
+    signal left[depth];
+    signal right[depth];
+    var node = leaf.out;
+    for (var i = 0; i < depth; i++) {
+        path_indices[i] * (path_indices[i] - 1) === 0;
+
+        left[i] <== node + path_indices[i] * (path_elements[i] - node);
+        right[i] <== path_elements[i] + node - left[i];
+
+        levels[i] = Poseidon(2);
+        levels[i].inputs[0] <== left[i];
+        levels[i].inputs[1] <== right[i];
+        node = levels[i].out;
+    }
+
+    allowlist_root === node;
+}
+
+// Up to 2^20 users.
+component main {public [allowlist_root, user_hi, user_lo]} = Allowlist(20);
@@ -0,0 +1,26 @@
+pragma circom 2.2.1;
+
+// Same statement as factor.circom, bound to the submitter: the signer pubkey
+// is a public input, split in its high and low 128 bits, so that a proof
+// copied from the mempool is invalid for any other signer.
+//
+// Public signals: [n, prover_hi, prover_lo].
+template FactorBound() {
+    signal input p;
+    signal input q;
+    signal input prover_hi;
+    signal input prover_lo;
+
+    signal output n;
+
+    n <== p * q;
+
+    // Inputs that are not part of any constraint are not bound by the proof,
+    // squaring them is the cheapest way to add one.
+    signal prover_hi_sq;
+    signal prover_lo_sq;
+    prover_hi_sq <== prover_hi * prover_hi;
+    prover_lo_sq <== prover_lo * prover_lo;
+}
+
+component main {public [prover_hi, prover_lo]} = FactorBound();
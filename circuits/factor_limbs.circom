@@ -0,0 +1,124 @@
+pragma circom 2.2.1;
+
+// Factoring of a modulus too large for a single BN254 scalar, e.g. an RSA
+// modulus, bound to the submitter like factor_bound.circom.
+//
+// n, p and q are k limbs of 64 bits, least significant first. The product is
+// computed column by column with carries, and every limb and carry is range
+// checked so that nothing wraps around the field: p * q = n holds over the
+// integers. p = 1 and q = 1 are rejected, so the factors are non-trivial.
+//
+// Public signals: [n[0], ..., n[k - 1], prover_hi, prover_lo].
+
+template Num2Bits(bits) {
+    signal input in;
+    signal output out[bits];
+
+    var sum = 0;
+    var e = 1;
+    for (var i = 0; i < bits; i++) {
+        out[i] <-- (in >> i) & 1;
+        out[i] * (out[i] - 1) === 0;
+        sum += out[i] * e;
+        e = e + e;
+    }
+    sum === in;
+}
+
+template IsZero() {
+    signal input in;
+    signal output out;
+
+    signal inv;
+    inv <-- in != 0 ? 1 / in : 0;
+    out <== 1 - in * inv;
+    in * out === 0;
+}
+
+// 1 when the k limbs of in are the integer 1.
+template IsOne(k) {
+    signal input in[k];
+    signal output out;
+
+    component limbs[k];
+    var matches = 0;
+    for (var i = 0; i < k; i++) {
+        limbs[i] = IsZero();
+        limbs[i].in <== i == 0 ? in[i] - 1 : in[i];
+        matches += limbs[i].out;
+    }
+
+    component all = IsZero();
+    all.in <== matches - k;
+    out <== all.out;
+}
+
+template FactorLimbs(k) {
+    signal input n[k];
+    signal input p[k];
+    signal input q[k];
+    signal input prover_hi;
+    signal input prover_lo;
+
+    component n_bits[k];
+    component p_bits[k];
+    component q_bits[k];
+    for (var i = 0; i < k; i++) {
+        n_bits[i] = Num2Bits(64);
+        n_bits[i].in <== n[i];
+        p_bits[i] = Num2Bits(64);
+        p_bits[i].in <== p[i];
+        q_bits[i] = Num2Bits(64);
+        q_bits[i].in <== q[i];
+    }
+
+    // Columns of the schoolbook product, each below k * 2^128.
+    signal products[k][k];
+    var columns[2 * k - 1];
+    for (var i = 0; i < 2 * k - 1; i++) {
+        columns[i] = 0;
+    }
+    for (var i = 0; i < k; i++) {
+        for (var j = 0; j < k; j++) {
+            products[i][j] <== p[i] * q[j];
+            columns[i + j] += products[i][j];
+        }
+    }
+
+    // columns[i] + carry[i - 1] = n[i] + carry[i] * 2^64, with n[i] = 0 past
+    // the limbs of n and no carry out of the last column. Carries stay below
+    // 2^72 for k up to 64.
+    signal carry[2 * k - 1];
+    component carry_bits[2 * k - 1];
+    var carry_in = 0;
+    for (var i = 0; i < 2 * k - 1; i++) {
+        var limb = 0;
+        if (i < k) {
+            limb = n[i];
+        }
+
+        carry[i] <-- (columns[i] + carry_in - limb) >> 64;
+        carry_bits[i] = Num2Bits(72);
+        carry_bits[i].in <== carry[i];
+        columns[i] + carry_in === limb + carry[i] * 2 ** 64;
+        carry_in = carry[i];
+    }
+    carry[2 * k - 2] === 0;
+
+    component p_is_one = IsOne(k);
+    p_is_one.in <== p;
+    p_is_one.out === 0;
+    component q_is_one = IsOne(k);
+    q_is_one.in <== q;
+    q_is_one.out === 0;
+
+    // Inputs that are not part of any constraint are not bound by the proof,
+    // squaring them is the cheapest way to add one.
+    signal prover_hi_sq;
+    signal prover_lo_sq;
+    prover_hi_sq <== prover_hi * prover_hi;
+    prover_lo_sq <== prover_lo * prover_lo;
+}
+
+// 2048-bit moduli, shorter ones are zero-padded to 32 limbs.
+component main {public [n, prover_hi, prover_lo]} = FactorLimbs(32);
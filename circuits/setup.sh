@@ -0,0 +1,33 @@
+#!/bin/sh
+# Compiles a circuit of this directory and runs its Groth16 setup, with a
+# phase 2 contribution so that gamma and delta differ:
+#
+#   sh circuits/setup.sh factor_bound
+#
+# Writes circuits/<name>.wasm, circuits/<name>.zkey and
+# circuits/<name>_verification_key.json, to register with `register_circuit`.
+# build.rs embeds circuits/factor_verification_key.json in the program.
+# POWER is the log2 of the largest constraint count of the Hermez powers of
+# tau, 16 is enough for every circuit here.
+set -eu
+
+name=$1
+power=${POWER:-16}
+dir=$(dirname "$0")
+build=$dir/../target/circuits
+
+mkdir -p "$build"
+ptau=$build/powersOfTau28_hez_final_$power.ptau
+if [ ! -f "$ptau" ]; then
+  curl -sSfL -o "$ptau" \
+    "https://storage.googleapis.com/zkevm/ptau/powersOfTau28_hez_final_$power.ptau"
+fi
+
+circom "$dir/$name.circom" --r1cs --wasm -l "$dir/../node_modules" -o "$build"
+
+npx snarkjs groth16 setup "$build/$name.r1cs" "$ptau" "$build/${name}_0000.zkey"
+npx snarkjs zkey contribute "$build/${name}_0000.zkey" "$dir/$name.zkey" \
+  --name="phase 2" -e="$(head -c 64 /dev/urandom | od -An -tx1 | tr -d ' \n')"
+npx snarkjs zkey export verificationkey "$dir/$name.zkey" \
+  "$dir/${name}_verification_key.json"
+cp "$build/${name}_js/$name.wasm" "$dir/$name.wasm"
@@ -6,4 +6,44 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptAuthority';
+export * from './accumulateInputs';
+export * from './beginVerification';
+export * from './cancelVerification';
+export * from './closeBuffer';
+export * from './closeChallenge';
+export * from './closeReceipt';
+export * from './createChallenge';
+export * from './createConfig';
+export * from './createTokenChallenge';
+export * from './deposit';
+export * from './finalizeVerification';
+export * from './freezeCircuit';
+export * from './initBuffer';
 export * from './initialize';
+export * from './initializeBatch';
+export * from './initializeCompressed';
+export * from './issueReceipt';
+export * from './lock';
+export * from './proposeAuthority';
+export * from './reclaimChallenge';
+export * from './reclaimTokenChallenge';
+export * from './registerCircuit';
+export * from './renounceAuthority';
+export * from './setAllowlistRoot';
+export * from './setCircuitEnabled';
+export * from './setFee';
+export * from './setSwapFee';
+export * from './setVerificationFee';
+export * from './solveChallenge';
+export * from './solveTokenChallenge';
+export * from './swap';
+export * from './unlock';
+export * from './updateCircuit';
+export * from './verifyFromBuffer';
+export * from './verifyGroth16';
+export * from './verifyProof';
+export * from './withdraw';
+export * from './withdrawFees';
+export * from './withdrawTokenFees';
+export * from './writeChunk';
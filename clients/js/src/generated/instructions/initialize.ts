@@ -27,10 +27,17 @@ import {
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
+  type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/web3.js';
 import { ZK_FACTOR_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getInitializeParamsDecoder,
+  getInitializeParamsEncoder,
+  type InitializeParams,
+  type InitializeParamsArgs,
+} from '../types';
 
 export const INITIALIZE_DISCRIMINATOR = new Uint8Array([
   175, 175, 109, 31, 13, 152, 155, 237,
@@ -43,6 +50,10 @@ export function getInitializeDiscriminatorBytes() {
 export type InitializeInstruction<
   TProgram extends string = typeof ZK_FACTOR_PROGRAM_ADDRESS,
   TAccountUser extends string | IAccountMeta<string> = string,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountTreasury extends string | IAccountMeta<string> = string,
+  TAccountCircuit extends string | IAccountMeta<string> = string,
+  TAccountNullifier extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
@@ -54,6 +65,18 @@ export type InitializeInstruction<
       TAccountUser extends string
         ? WritableSignerAccount<TAccountUser> & IAccountSignerMeta<TAccountUser>
         : TAccountUser,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountTreasury extends string
+        ? WritableAccount<TAccountTreasury>
+        : TAccountTreasury,
+      TAccountCircuit extends string
+        ? ReadonlyAccount<TAccountCircuit>
+        : TAccountCircuit,
+      TAccountNullifier extends string
+        ? WritableAccount<TAccountNullifier>
+        : TAccountNullifier,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -63,24 +86,16 @@ export type InitializeInstruction<
 
 export type InitializeInstructionData = {
   discriminator: ReadonlyUint8Array;
-  proofA: ReadonlyUint8Array;
-  proofB: ReadonlyUint8Array;
-  proofC: ReadonlyUint8Array;
+  input: InitializeParams;
 };
 
-export type InitializeInstructionDataArgs = {
-  proofA: ReadonlyUint8Array;
-  proofB: ReadonlyUint8Array;
-  proofC: ReadonlyUint8Array;
-};
+export type InitializeInstructionDataArgs = { input: InitializeParamsArgs };
 
 export function getInitializeInstructionDataEncoder(): Encoder<InitializeInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
-      ['proofA', fixEncoderSize(getBytesEncoder(), 64)],
-      ['proofB', fixEncoderSize(getBytesEncoder(), 128)],
-      ['proofC', fixEncoderSize(getBytesEncoder(), 64)],
+      ['input', getInitializeParamsEncoder()],
     ]),
     (value) => ({ ...value, discriminator: INITIALIZE_DISCRIMINATOR })
   );
@@ -89,9 +104,7 @@ export function getInitializeInstructionDataEncoder(): Encoder<InitializeInstruc
 export function getInitializeInstructionDataDecoder(): Decoder<InitializeInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
-    ['proofA', fixDecoderSize(getBytesDecoder(), 64)],
-    ['proofB', fixDecoderSize(getBytesDecoder(), 128)],
-    ['proofC', fixDecoderSize(getBytesDecoder(), 64)],
+    ['input', getInitializeParamsDecoder()],
   ]);
 }
 
@@ -107,29 +120,58 @@ export function getInitializeInstructionDataCodec(): Codec<
 
 export type InitializeInput<
   TAccountUser extends string = string,
+  TAccountConfig extends string = string,
+  TAccountTreasury extends string = string,
+  TAccountCircuit extends string = string,
+  TAccountNullifier extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   user: TransactionSigner<TAccountUser>;
+  config: Address<TAccountConfig>;
+  treasury: Address<TAccountTreasury>;
+  circuit: Address<TAccountCircuit>;
+  nullifier: Address<TAccountNullifier>;
   systemProgram?: Address<TAccountSystemProgram>;
-  proofA: InitializeInstructionDataArgs['proofA'];
-  proofB: InitializeInstructionDataArgs['proofB'];
-  proofC: InitializeInstructionDataArgs['proofC'];
+  input: InitializeInstructionDataArgs['input'];
 };
 
 export function getInitializeInstruction<
   TAccountUser extends string,
+  TAccountConfig extends string,
+  TAccountTreasury extends string,
+  TAccountCircuit extends string,
+  TAccountNullifier extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof ZK_FACTOR_PROGRAM_ADDRESS,
 >(
-  input: InitializeInput<TAccountUser, TAccountSystemProgram>,
+  input: InitializeInput<
+    TAccountUser,
+    TAccountConfig,
+    TAccountTreasury,
+    TAccountCircuit,
+    TAccountNullifier,
+    TAccountSystemProgram
+  >,
   config?: { programAddress?: TProgramAddress }
-): InitializeInstruction<TProgramAddress, TAccountUser, TAccountSystemProgram> {
+): InitializeInstruction<
+  TProgramAddress,
+  TAccountUser,
+  TAccountConfig,
+  TAccountTreasury,
+  TAccountCircuit,
+  TAccountNullifier,
+  TAccountSystemProgram
+> {
   // Program address.
   const programAddress = config?.programAddress ?? ZK_FACTOR_PROGRAM_ADDRESS;
 
   // Original accounts.
   const originalAccounts = {
     user: { value: input.user ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
+    treasury: { value: input.treasury ?? null, isWritable: true },
+    circuit: { value: input.circuit ?? null, isWritable: false },
+    nullifier: { value: input.nullifier ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -150,6 +192,10 @@ export function getInitializeInstruction<
   const instruction = {
     accounts: [
       getAccountMeta(accounts.user),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.treasury),
+      getAccountMeta(accounts.circuit),
+      getAccountMeta(accounts.nullifier),
       getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
@@ -159,6 +205,10 @@ export function getInitializeInstruction<
   } as InitializeInstruction<
     TProgramAddress,
     TAccountUser,
+    TAccountConfig,
+    TAccountTreasury,
+    TAccountCircuit,
+    TAccountNullifier,
     TAccountSystemProgram
   >;
 
@@ -172,7 +222,11 @@ export type ParsedInitializeInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     user: TAccountMetas[0];
-    systemProgram: TAccountMetas[1];
+    config: TAccountMetas[1];
+    treasury: TAccountMetas[2];
+    circuit: TAccountMetas[3];
+    nullifier: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
   };
   data: InitializeInstructionData;
 };
@@ -185,7 +239,7 @@ export function parseInitializeInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedInitializeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -199,6 +253,10 @@ export function parseInitializeInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       user: getNextAccount(),
+      config: getNextAccount(),
+      treasury: getNextAccount(),
+      circuit: getNextAccount(),
+      nullifier: getNextAccount(),
       systemProgram: getNextAccount(),
     },
     data: getInitializeInstructionDataDecoder().decode(instruction.data),
@@ -13,19 +13,363 @@ import {
   type Address,
   type ReadonlyUint8Array,
 } from '@solana/web3.js';
-import { type ParsedInitializeInstruction } from '../instructions';
+import {
+  type ParsedAcceptAuthorityInstruction,
+  type ParsedAccumulateInputsInstruction,
+  type ParsedBeginVerificationInstruction,
+  type ParsedCancelVerificationInstruction,
+  type ParsedCloseBufferInstruction,
+  type ParsedCloseChallengeInstruction,
+  type ParsedCloseReceiptInstruction,
+  type ParsedCreateChallengeInstruction,
+  type ParsedCreateConfigInstruction,
+  type ParsedCreateTokenChallengeInstruction,
+  type ParsedDepositInstruction,
+  type ParsedFinalizeVerificationInstruction,
+  type ParsedFreezeCircuitInstruction,
+  type ParsedInitBufferInstruction,
+  type ParsedInitializeBatchInstruction,
+  type ParsedInitializeCompressedInstruction,
+  type ParsedInitializeInstruction,
+  type ParsedIssueReceiptInstruction,
+  type ParsedLockInstruction,
+  type ParsedProposeAuthorityInstruction,
+  type ParsedReclaimChallengeInstruction,
+  type ParsedReclaimTokenChallengeInstruction,
+  type ParsedRegisterCircuitInstruction,
+  type ParsedRenounceAuthorityInstruction,
+  type ParsedSetAllowlistRootInstruction,
+  type ParsedSetCircuitEnabledInstruction,
+  type ParsedSetFeeInstruction,
+  type ParsedSetSwapFeeInstruction,
+  type ParsedSetVerificationFeeInstruction,
+  type ParsedSolveChallengeInstruction,
+  type ParsedSolveTokenChallengeInstruction,
+  type ParsedSwapInstruction,
+  type ParsedUnlockInstruction,
+  type ParsedUpdateCircuitInstruction,
+  type ParsedVerifyFromBufferInstruction,
+  type ParsedVerifyGroth16Instruction,
+  type ParsedVerifyProofInstruction,
+  type ParsedWithdrawFeesInstruction,
+  type ParsedWithdrawInstruction,
+  type ParsedWithdrawTokenFeesInstruction,
+  type ParsedWriteChunkInstruction,
+} from '../instructions';
 
 export const ZK_FACTOR_PROGRAM_ADDRESS =
   'zk1PtMyK25jdTA56c7duVC7Zk2gPKy2Y3PiYhJ4L7ro' as Address<'zk1PtMyK25jdTA56c7duVC7Zk2gPKy2Y3PiYhJ4L7ro'>;
 
+export enum ZkFactorAccount {
+  Challenge,
+  Circuit,
+  Nullifier,
+  ProofBuffer,
+  ProofReceipt,
+  Treasury,
+  VerificationSession,
+  ZkFactorConfig,
+}
+
+export function identifyZkFactorAccount(
+  account: { data: ReadonlyUint8Array } | ReadonlyUint8Array
+): ZkFactorAccount {
+  const data = 'data' in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([119, 250, 161, 121, 119, 81, 22, 208])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorAccount.Challenge;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([113, 209, 5, 225, 233, 216, 248, 61])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorAccount.Circuit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([18, 56, 142, 165, 181, 158, 187, 133])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorAccount.Nullifier;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([71, 133, 225, 94, 9, 130, 40, 161])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorAccount.ProofBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([235, 183, 148, 241, 160, 127, 228, 196])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorAccount.ProofReceipt;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([238, 239, 123, 238, 89, 1, 168, 253])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorAccount.Treasury;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([247, 69, 199, 26, 112, 195, 243, 254])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorAccount.VerificationSession;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([227, 162, 219, 135, 217, 64, 168, 134])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorAccount.ZkFactorConfig;
+  }
+  throw new Error(
+    'The provided account could not be identified as a zkFactor account.'
+  );
+}
+
 export enum ZkFactorInstruction {
+  AcceptAuthority,
+  AccumulateInputs,
+  BeginVerification,
+  CancelVerification,
+  CloseBuffer,
+  CloseChallenge,
+  CloseReceipt,
+  CreateChallenge,
+  CreateConfig,
+  CreateTokenChallenge,
+  Deposit,
+  FinalizeVerification,
+  FreezeCircuit,
+  InitBuffer,
   Initialize,
+  InitializeBatch,
+  InitializeCompressed,
+  IssueReceipt,
+  Lock,
+  ProposeAuthority,
+  ReclaimChallenge,
+  ReclaimTokenChallenge,
+  RegisterCircuit,
+  RenounceAuthority,
+  SetAllowlistRoot,
+  SetCircuitEnabled,
+  SetFee,
+  SetSwapFee,
+  SetVerificationFee,
+  SolveChallenge,
+  SolveTokenChallenge,
+  Swap,
+  Unlock,
+  UpdateCircuit,
+  VerifyFromBuffer,
+  VerifyGroth16,
+  VerifyProof,
+  Withdraw,
+  WithdrawFees,
+  WithdrawTokenFees,
+  WriteChunk,
 }
 
 export function identifyZkFactorInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): ZkFactorInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([107, 86, 198, 91, 33, 12, 107, 160])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.AcceptAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([36, 213, 133, 40, 241, 34, 118, 153])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.AccumulateInputs;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([6, 173, 175, 164, 204, 186, 106, 218])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.BeginVerification;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([121, 229, 53, 171, 69, 69, 248, 140])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.CancelVerification;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([46, 114, 179, 58, 57, 45, 194, 172])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.CloseBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([29, 156, 109, 17, 41, 99, 71, 236])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.CloseChallenge;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([126, 254, 244, 203, 124, 164, 134, 89])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.CloseReceipt;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([170, 244, 47, 1, 1, 15, 173, 239])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.CreateChallenge;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([201, 207, 243, 114, 75, 111, 47, 189])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.CreateConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([45, 103, 79, 48, 189, 248, 31, 248])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.CreateTokenChallenge;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([242, 35, 198, 137, 82, 225, 242, 182])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.Deposit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([17, 156, 245, 109, 209, 118, 72, 240])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.FinalizeVerification;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([63, 183, 191, 150, 191, 119, 165, 41])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.FreezeCircuit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([123, 211, 233, 210, 166, 139, 218, 60])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.InitBuffer;
+  }
   if (
     containsBytes(
       data,
@@ -37,6 +381,292 @@ export function identifyZkFactorInstruction(
   ) {
     return ZkFactorInstruction.Initialize;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([126, 44, 205, 90, 220, 105, 105, 193])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.InitializeBatch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([58, 32, 220, 140, 220, 145, 247, 130])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.InitializeCompressed;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([128, 231, 32, 119, 210, 139, 80, 68])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.IssueReceipt;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([21, 19, 208, 43, 237, 62, 255, 87])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.Lock;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([20, 148, 236, 198, 76, 119, 99, 142])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.ProposeAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([199, 39, 36, 43, 94, 134, 149, 57])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.ReclaimChallenge;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([84, 31, 27, 66, 122, 170, 28, 246])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.ReclaimTokenChallenge;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([208, 247, 241, 136, 81, 166, 206, 199])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.RegisterCircuit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([78, 110, 117, 127, 89, 23, 253, 153])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.RenounceAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([145, 238, 252, 173, 15, 3, 94, 23])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.SetAllowlistRoot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([12, 197, 13, 224, 26, 196, 107, 45])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.SetCircuitEnabled;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([18, 154, 24, 18, 237, 214, 19, 80])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.SetFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([144, 1, 134, 163, 144, 80, 0, 82])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.SetSwapFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([212, 208, 144, 146, 36, 205, 212, 252])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.SetVerificationFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([143, 211, 119, 108, 97, 26, 172, 226])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.SolveChallenge;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([88, 242, 23, 45, 167, 193, 119, 114])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.SolveTokenChallenge;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([248, 198, 158, 145, 225, 117, 135, 200])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.Swap;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([101, 155, 40, 21, 158, 189, 56, 203])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.Unlock;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([11, 32, 139, 120, 14, 155, 14, 62])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.UpdateCircuit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([95, 53, 132, 130, 149, 135, 167, 71])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.VerifyFromBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([228, 26, 135, 7, 19, 253, 172, 97])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.VerifyGroth16;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([217, 211, 191, 110, 144, 13, 186, 98])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.VerifyProof;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([183, 18, 70, 156, 148, 109, 161, 34])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.Withdraw;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([198, 212, 171, 109, 144, 215, 174, 89])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.WithdrawFees;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([148, 11, 90, 7, 99, 98, 153, 104])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.WithdrawTokenFees;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([93, 141, 167, 15, 209, 133, 137, 51])
+      ),
+      0
+    )
+  ) {
+    return ZkFactorInstruction.WriteChunk;
+  }
   throw new Error(
     'The provided instruction could not be identified as a zkFactor instruction.'
   );
@@ -44,6 +674,127 @@ export function identifyZkFactorInstruction(
 
 export type ParsedZkFactorInstruction<
   TProgram extends string = 'zk1PtMyK25jdTA56c7duVC7Zk2gPKy2Y3PiYhJ4L7ro',
-> = {
-  instructionType: ZkFactorInstruction.Initialize;
-} & ParsedInitializeInstruction<TProgram>;
+> =
+  | ({
+      instructionType: ZkFactorInstruction.AcceptAuthority;
+    } & ParsedAcceptAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.AccumulateInputs;
+    } & ParsedAccumulateInputsInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.BeginVerification;
+    } & ParsedBeginVerificationInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.CancelVerification;
+    } & ParsedCancelVerificationInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.CloseBuffer;
+    } & ParsedCloseBufferInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.CloseChallenge;
+    } & ParsedCloseChallengeInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.CloseReceipt;
+    } & ParsedCloseReceiptInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.CreateChallenge;
+    } & ParsedCreateChallengeInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.CreateConfig;
+    } & ParsedCreateConfigInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.CreateTokenChallenge;
+    } & ParsedCreateTokenChallengeInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.Deposit;
+    } & ParsedDepositInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.FinalizeVerification;
+    } & ParsedFinalizeVerificationInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.FreezeCircuit;
+    } & ParsedFreezeCircuitInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.InitBuffer;
+    } & ParsedInitBufferInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.Initialize;
+    } & ParsedInitializeInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.InitializeBatch;
+    } & ParsedInitializeBatchInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.InitializeCompressed;
+    } & ParsedInitializeCompressedInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.IssueReceipt;
+    } & ParsedIssueReceiptInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.Lock;
+    } & ParsedLockInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.ProposeAuthority;
+    } & ParsedProposeAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.ReclaimChallenge;
+    } & ParsedReclaimChallengeInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.ReclaimTokenChallenge;
+    } & ParsedReclaimTokenChallengeInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.RegisterCircuit;
+    } & ParsedRegisterCircuitInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.RenounceAuthority;
+    } & ParsedRenounceAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.SetAllowlistRoot;
+    } & ParsedSetAllowlistRootInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.SetCircuitEnabled;
+    } & ParsedSetCircuitEnabledInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.SetFee;
+    } & ParsedSetFeeInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.SetSwapFee;
+    } & ParsedSetSwapFeeInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.SetVerificationFee;
+    } & ParsedSetVerificationFeeInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.SolveChallenge;
+    } & ParsedSolveChallengeInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.SolveTokenChallenge;
+    } & ParsedSolveTokenChallengeInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.Swap;
+    } & ParsedSwapInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.Unlock;
+    } & ParsedUnlockInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.UpdateCircuit;
+    } & ParsedUpdateCircuitInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.VerifyFromBuffer;
+    } & ParsedVerifyFromBufferInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.VerifyGroth16;
+    } & ParsedVerifyGroth16Instruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.VerifyProof;
+    } & ParsedVerifyProofInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.Withdraw;
+    } & ParsedWithdrawInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.WithdrawFees;
+    } & ParsedWithdrawFeesInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.WithdrawTokenFees;
+    } & ParsedWithdrawTokenFeesInstruction<TProgram>)
+  | ({
+      instructionType: ZkFactorInstruction.WriteChunk;
+    } & ParsedWriteChunkInstruction<TProgram>);
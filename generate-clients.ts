@@ -1,4 +1,4 @@
-import { createFromRoot } from "codama";
+import { camelCase, createFromRoot, updateDefinedTypesVisitor } from "codama";
 import { renderVisitor as renderJavaScriptVisitor } from "@codama/renderers-js";
 import * as Bun from "bun";
 import path from "node:path";
@@ -9,6 +9,16 @@ const idlFile = Bun.file("./target/idl/zk_factor.json");
 const idl = await idlFile.json();
 const codama = createFromRoot(rootNodeFromAnchor(idl));
 
+// Instruction arguments named `<Instruction>Input` clash with the generated
+// `<Instruction>Input` of the instruction builders, render them as `Params`.
+const renames = Object.fromEntries(
+  idl.instructions.map((ix: { name: string }) => [
+    camelCase(`${ix.name}_input`),
+    { name: camelCase(`${ix.name}_params`) },
+  ]),
+);
+codama.update(updateDefinedTypesVisitor(renames));
+
 // Render JavaScript.
 const jsClient = "./clients/js";
 codama.accept(renderJavaScriptVisitor(path.join(jsClient, "src", "generated")));
@@ -5,8 +5,11 @@ import {
   createSolanaRpcSubscriptions,
   createTransactionMessage,
   devnet,
+  getAddressEncoder,
   generateKeyPairSigner,
+  getProgramDerivedAddress,
   getSignatureFromTransaction,
+  getU64Encoder,
   lamports,
   pipe,
   sendAndConfirmTransactionFactory,
@@ -15,15 +18,26 @@ import {
   signTransactionMessageWithSigners,
 } from "@solana/web3.js";
 import { createLogger, explorerUrl } from "./utils/helpers.ts";
-import { LAMPORTS_PER_SOL } from "./utils/constants.ts";
-import { getInitializeInstruction } from "./clients/js/src/generated/index.ts";
+import { CONFIG_SEED, LAMPORTS_PER_SOL } from "./utils/constants.ts";
+import {
+  getInitializeInstruction,
+  ZK_FACTOR_PROGRAM_ADDRESS,
+} from "./clients/js/src/generated/index.ts";
 import * as snarkjs from "snarkjs";
 import * as Bun from "bun";
-import { g1Uncompressed, proofToUint8Array } from "./utils/zk.ts";
+import {
+  g1Uncompressed,
+  proofNullifier,
+  proofToUint8Array,
+} from "./utils/zk.ts";
 // @ts-ignore - ffjavascript is not typed
 import { getCurveFromName } from "ffjavascript";
 import { unstringifyBigInts } from "./utils/ff.ts";
-import { prepare_g1_point, prepare_proofs } from "zk-utils";
+import {
+  prepare_g1_point,
+  prepare_proofs,
+  pubkey_to_public_inputs,
+} from "zk-utils";
 
 const log = createLogger("zk-factor");
 const curve = await getCurveFromName("bn128", { singleThread: true });
@@ -67,15 +81,20 @@ const airdrop = airdropFactory({ rpc, rpcSubscriptions });
 {
   // just to simulate here being in a browser
   const wasmFileArrayBuffer = await Bun.file(
-    "./circuits/factor.wasm",
+    "./circuits/factor_bound.wasm",
   ).arrayBuffer();
   const wasmFile = new Uint8Array(wasmFileArrayBuffer);
   const zkeyFileArrayBuffer = await Bun.file(
-    "./circuits/factor.zkey",
+    "./circuits/factor_bound.zkey",
   ).arrayBuffer();
   const zkeyFile = new Uint8Array(zkeyFileArrayBuffer);
+  // The proof is bound to the signer, it is only valid in a transaction
+  // where `keypair` is the `user` of `initialize`.
+  const { prover_hi, prover_lo } = pubkey_to_public_inputs(
+    new Uint8Array(getAddressEncoder().encode(keypair.address)),
+  );
   const { proof } = await snarkjs.groth16.fullProve(
-    { p: 7, q: 191 },
+    { p: 7, q: 191, prover_hi, prover_lo },
     wasmFile,
     zkeyFile,
     undefined,
@@ -87,15 +106,43 @@ const airdrop = airdropFactory({ rpc, rpcSubscriptions });
 
   const preparedProof = prepare_proofs(proof);
 
-  // write raw proof to a file
-  Bun.write("./raw_proof.json", JSON.stringify(preparedProof.raw));
+  // write the proof to its own file, raw_proof.json is a test fixture
+  Bun.write("./factor_bound_proof.json", JSON.stringify(preparedProof.raw));
+
+  const proofA = new Uint8Array(preparedProof.proof_a);
+  const proofB = new Uint8Array(preparedProof.proof_b);
+  const proofC = new Uint8Array(preparedProof.proof_c);
+
+  // The config must exist and have the `factor_bound` circuit registered,
+  // see `create_config` and `register_circuit`.
+  const findAddress = async (seeds: (string | Uint8Array)[]) =>
+    (
+      await getProgramDerivedAddress({
+        programAddress: ZK_FACTOR_PROGRAM_ADDRESS,
+        seeds,
+      })
+    )[0];
+  const addressEncoder = getAddressEncoder();
+  const config = await findAddress([
+    "config",
+    new Uint8Array(getU64Encoder().encode(CONFIG_SEED)),
+  ]);
+  const configBytes = new Uint8Array(addressEncoder.encode(config));
+  const circuitId = new Uint8Array(32);
+  circuitId.set(new TextEncoder().encode("factor_bound"));
 
   // Using codama's generated code, we can build the instruction as follows:
   const instruction = getInitializeInstruction({
     user: keypair,
-    proofA: new Uint8Array(preparedProof.proof_a),
-    proofB: new Uint8Array(preparedProof.proof_b),
-    proofC: new Uint8Array(preparedProof.proof_c),
+    config,
+    treasury: await findAddress(["treasury", configBytes]),
+    circuit: await findAddress(["circuit", configBytes, circuitId]),
+    nullifier: await findAddress([
+      "nullifier",
+      configBytes,
+      proofNullifier(proofA, proofB, proofC),
+    ]),
+    input: { proofA, proofB, proofC },
   });
 
   // We now build the transaction message:
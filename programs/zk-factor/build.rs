@@ -0,0 +1,98 @@
+//! Generates the `Groth16Verifyingkey` consts from the snarkjs
+//! `<circuit>_verification_key.json` files that `circuits/setup.sh` writes,
+//! so that re-running the trusted setup is enough to update the program.
+
+use std::{env, fs, path::PathBuf};
+
+use num_bigint::BigUint;
+use serde_json::Value;
+
+/// (verification key file in `circuits/`, generated file in `OUT_DIR`, const name)
+const VERIFYING_KEYS: &[(&str, &str, &str)] = &[(
+    "factor_verification_key.json",
+    "factor_vk.rs",
+    "VERIFYINGKEY",
+)];
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let circuits_dir = env::var("ZK_FACTOR_CIRCUITS_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| manifest_dir.join("../../circuits"));
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    println!("cargo:rerun-if-env-changed=ZK_FACTOR_CIRCUITS_DIR");
+
+    for (vk_file, out_file, const_name) in VERIFYING_KEYS {
+        let vk_path = circuits_dir.join(vk_file);
+        println!("cargo:rerun-if-changed={}", vk_path.display());
+
+        let vk = fs::read_to_string(&vk_path)
+            .unwrap_or_else(|err| panic!("failed to read {}: {err}", vk_path.display()));
+        let vk: Value = serde_json::from_str(&vk)
+            .unwrap_or_else(|err| panic!("failed to parse {}: {err}", vk_path.display()));
+
+        fs::write(out_dir.join(out_file), render(&vk, const_name))
+            .unwrap_or_else(|err| panic!("failed to write {out_file}: {err}"));
+    }
+}
+
+fn render(vk: &Value, const_name: &str) -> String {
+    assert_eq!(vk["protocol"], "groth16", "only groth16 keys are supported");
+    assert_eq!(vk["curve"], "bn128", "only bn128 keys are supported");
+
+    let nr_pubinputs = vk["nPublic"].as_u64().expect("nPublic") as usize;
+    let ic = vk["IC"].as_array().expect("IC");
+    assert_eq!(
+        ic.len(),
+        nr_pubinputs + 1,
+        "IC must contain nPublic + 1 points"
+    );
+
+    let mut s = String::from("// @generated by build.rs, do not edit.\n\n");
+    s += &format!("pub const {const_name}: Groth16Verifyingkey = Groth16Verifyingkey {{\n");
+    s += &format!("    nr_pubinputs: {nr_pubinputs},\n");
+    s += &format!("    vk_alpha_g1: {:?},\n", g1(&vk["vk_alpha_1"]));
+    s += &format!("    vk_beta_g2: {:?},\n", g2(&vk["vk_beta_2"]));
+    s += &format!("    vk_gamme_g2: {:?},\n", g2(&vk["vk_gamma_2"]));
+    s += &format!("    vk_delta_g2: {:?},\n", g2(&vk["vk_delta_2"]));
+    s += "    vk_ic: &[\n";
+    for point in ic {
+        s += &format!("        {:?},\n", g1(point));
+    }
+    s += "    ],\n};\n";
+    s
+}
+
+/// snarkjs `[x, y, z]` to the big-endian `x || y` expected by the syscalls.
+fn g1(point: &Value) -> Vec<u8> {
+    [field(&point[0]), field(&point[1])].concat()
+}
+
+/// snarkjs `[[x_c0, x_c1], [y_c0, y_c1], [z_c0, z_c1]]` to the big-endian
+/// `x_c1 || x_c0 || y_c1 || y_c0` expected by the syscalls.
+fn g2(point: &Value) -> Vec<u8> {
+    [
+        field(&point[0][1]),
+        field(&point[0][0]),
+        field(&point[1][1]),
+        field(&point[1][0]),
+    ]
+    .concat()
+}
+
+fn field(value: &Value) -> Vec<u8> {
+    let decimal = value.as_str().expect("field elements are decimal strings");
+    let bytes = decimal
+        .parse::<BigUint>()
+        .unwrap_or_else(|err| panic!("invalid field element {decimal}: {err}"))
+        .to_bytes_be();
+    assert!(
+        bytes.len() <= 32,
+        "field element {decimal} is over 32 bytes"
+    );
+
+    let mut out = vec![0u8; 32 - bytes.len()];
+    out.extend_from_slice(&bytes);
+    out
+}
@@ -1,4 +1,50 @@
-use crate::public_input;
+use crate::{circuit_id, u64_to_scalar};
 
 pub const DISCRIMINATOR: usize = 8;
-pub const PUBLIC_INPUT: [[u8; 32]; 1] = public_input!(1337, 2);
+pub const PUBLIC_INPUT: [[u8; 32]; 1] = [u64_to_scalar(1337)];
+
+/// Bits per limb of the integers split over several public inputs.
+///
+/// 64 rather than 120 bits: a limb is a plain `u64` in instruction data and
+/// accounts, and in the circuit the product of two limbs fits in 128 bits, so
+/// the columns and carries of a 2048-bit product stay far below the 254-bit
+/// field. 120-bit limbs would save public inputs (18 instead of 32 for 2048
+/// bits) but need `u128` limbs and leave little headroom for the carries.
+pub const LIMB_BITS: usize = 64;
+
+/// Maximum number of limbs of a challenge modulus: 2048 bits.
+pub const MAX_MODULUS_LIMBS: usize = 32;
+
+/// Maximum number of public inputs of a verifying key stored on-chain, enough
+/// for the limbs of the largest challenge modulus and the solver pubkey.
+pub const MAX_PUBLIC_INPUTS: usize = MAX_MODULUS_LIMBS + 2;
+
+/// Fees are expressed in basis points.
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+/// How long a proof receipt stays valid, in seconds.
+pub const RECEIPT_VALIDITY: i64 = 30 * 24 * 60 * 60;
+
+/// Decimals of the LP mint of the pool.
+pub const LP_DECIMALS: u8 = 6;
+
+/// LP locked in the pool by the first deposit. The supply can then never
+/// drop back to a few units, which would let the first depositor inflate the
+/// value of a share by donating to the vaults and round later deposits down
+/// to nothing.
+pub const MINIMUM_LIQUIDITY: u64 = 1_000;
+
+pub const FACTOR_CIRCUIT_ID: [u8; 32] = circuit_id("factor");
+pub const FACTOR_BOUND_CIRCUIT_ID: [u8; 32] = circuit_id("factor_bound");
+pub const FACTOR_LIMBS_CIRCUIT_ID: [u8; 32] = circuit_id("factor_limbs");
+pub const ALLOWLIST_CIRCUIT_ID: [u8; 32] = circuit_id("allowlist");
+
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const LP_SEED: &[u8] = b"lp";
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const CIRCUIT_SEED: &[u8] = b"circuit";
+pub const CHALLENGE_SEED: &[u8] = b"challenge";
+pub const NULLIFIER_SEED: &[u8] = b"nullifier";
+pub const RECEIPT_SEED: &[u8] = b"receipt";
+pub const SESSION_SEED: &[u8] = b"session";
+pub const BUFFER_SEED: &[u8] = b"buffer";
@@ -4,4 +4,52 @@ use anchor_lang::prelude::*;
 pub enum ZkFactorError {
     #[msg("Pool is locked")]
     PoolLocked,
+    #[msg("Signer is not the config authority")]
+    Unauthorized,
+    #[msg("Circuit is frozen")]
+    CircuitFrozen,
+    #[msg("Invalid number of public inputs for an on-chain verifying key")]
+    InvalidNrPublicInputs,
+    #[msg("Circuit is disabled")]
+    CircuitDisabled,
+    #[msg("Modulus must be 1 to 32 limbs above 1, without leading zero limb")]
+    InvalidModulus,
+    #[msg("Reward must be greater than zero")]
+    InvalidReward,
+    #[msg("Challenge is already solved")]
+    ChallengeAlreadySolved,
+    #[msg("Math overflow")]
+    MathOverflow,
+    #[msg("Proof has already been used")]
+    ProofAlreadyUsed,
+    #[msg("Expected one nullifier account per proof")]
+    InvalidNullifierAccounts,
+    #[msg("Receipt has not expired yet")]
+    ReceiptNotExpired,
+    #[msg("Circuit was updated since the verification session or challenge started")]
+    CircuitUpdated,
+    #[msg("Verification session is missing public inputs")]
+    SessionIncomplete,
+    #[msg("Chunk is out of the buffer bounds")]
+    BufferOverflow,
+    #[msg("Fee must not exceed 10000 basis points")]
+    InvalidFee,
+    #[msg("Not enough fees in the treasury")]
+    InsufficientFees,
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("Slippage exceeded")]
+    SlippageExceeded,
+    #[msg("Deadline must be in the future")]
+    InvalidDeadline,
+    #[msg("Challenge deadline has passed")]
+    ChallengeExpired,
+    #[msg("Challenge deadline has not passed yet")]
+    ChallengeNotExpired,
+    #[msg("Public input is not a decimal number")]
+    InvalidPublicInput,
+    #[msg("Challenge has not been solved")]
+    ChallengeNotSolved,
+    #[msg("Allowlist root is not a valid BN254 scalar")]
+    InvalidAllowlistRoot,
 }
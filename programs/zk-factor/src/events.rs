@@ -0,0 +1,37 @@
+use anchor_lang::{prelude::*, solana_program::compute_units::sol_remaining_compute_units};
+
+/// Emitted for every proof the program accepts.
+#[event]
+pub struct ProofVerified {
+    pub prover: Pubkey,
+    pub circuit: Pubkey,
+    /// Empty for two-phase verifications, which only keep the folded inputs.
+    pub public_inputs: Vec<[u8; 32]>,
+    /// Hash chain of the inputs of a two-phase verification, see
+    /// [`crate::chain_inputs_hash`]. `None` when `public_inputs` is set.
+    pub inputs_hash: Option<[u8; 32]>,
+    /// Compute units spent by the instruction up to the event, for a batch
+    /// this is the cost of the whole batch.
+    pub compute_units: u64,
+}
+
+#[event]
+pub struct ChallengeSolved {
+    pub challenge: Pubkey,
+    pub solver: Pubkey,
+    pub modulus: Vec<u64>,
+    /// `None` when the reward is in lamports.
+    pub mint: Option<Pubkey>,
+    pub payout: u64,
+    pub fee: u64,
+}
+
+/// Remaining compute units, to measure the cost of an instruction with
+/// [`compute_units_since`].
+pub fn compute_units_start() -> u64 {
+    sol_remaining_compute_units()
+}
+
+pub fn compute_units_since(start: u64) -> u64 {
+    start.saturating_sub(sol_remaining_compute_units())
+}
@@ -0,0 +1,81 @@
+//! BN254 operations implemented with arkworks, for running the verifiers off
+//! chain without the `alt_bn128` syscalls, e.g. in indexers or relayers.
+//!
+//! Points are decoded like the syscalls do: all zeros is the identity, and
+//! anything else must have coordinates in the field, be on the curve and,
+//! for G2, be in the prime order subgroup.
+
+use ark_bn254::{Bn254, Fq12, G1Affine, G2Affine};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{BigInt, BigInteger, One, PrimeField};
+
+use super::{decode_g1, decode_g2, Groth16Error, PointError};
+
+/// `point * scalar`, both big-endian. Like the syscall, the scalar is not
+/// reduced modulo the group order.
+pub fn g1_mul(point: &[u8; 64], scalar: &[u8; 32]) -> Result<[u8; 64], Groth16Error> {
+    let point = g1(point).map_err(|_| Groth16Error::PreparingInputsG1MulFailed)?;
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(scalar.rchunks(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+
+    Ok(g1_to_be_bytes(
+        &point.mul_bigint(BigInt(limbs)).into_affine(),
+    ))
+}
+
+/// `left + right`, both big-endian.
+pub fn g1_add(left: &[u8; 64], right: &[u8; 64]) -> Result<[u8; 64], Groth16Error> {
+    let left = g1(left).map_err(|_| Groth16Error::PreparingInputsG1AdditionFailed)?;
+    let right = g1(right).map_err(|_| Groth16Error::PreparingInputsG1AdditionFailed)?;
+
+    Ok(g1_to_be_bytes(&(left + right).into_affine()))
+}
+
+/// Whether the product of the pairings of the big-endian `G1 || G2` pairs in
+/// `input` is one.
+pub fn pairing_check(input: &[u8]) -> Result<bool, Groth16Error> {
+    let (pairs, remainder) = input.as_chunks::<192>();
+    if !remainder.is_empty() {
+        return Err(Groth16Error::ProofVerificationFailed);
+    }
+
+    let mut g1s = Vec::with_capacity(pairs.len());
+    let mut g2s = Vec::with_capacity(pairs.len());
+    for pair in pairs {
+        let (p, q) = pair.split_at(64);
+        g1s.push(g1(p.try_into().unwrap()).map_err(|_| Groth16Error::ProofVerificationFailed)?);
+        g2s.push(g2(q.try_into().unwrap()).map_err(|_| Groth16Error::ProofVerificationFailed)?);
+    }
+
+    Ok(Bn254::multi_pairing(g1s, g2s).0 == Fq12::one())
+}
+
+/// Whether a big-endian G2 point is in the prime order subgroup.
+pub fn g2_in_subgroup(point: &[u8; 128]) -> bool {
+    !matches!(decode_g2(point), Err(PointError::NotInSubgroup))
+}
+
+fn g1(bytes: &[u8; 64]) -> Result<G1Affine, PointError> {
+    match decode_g1(bytes) {
+        Err(PointError::Identity) => Ok(G1Affine::zero()),
+        point => point,
+    }
+}
+
+fn g2(bytes: &[u8; 128]) -> Result<G2Affine, PointError> {
+    match decode_g2(bytes) {
+        Err(PointError::Identity) => Ok(G2Affine::zero()),
+        point => point,
+    }
+}
+
+fn g1_to_be_bytes(point: &G1Affine) -> [u8; 64] {
+    let mut bytes = [0u8; 64];
+    if let Some((x, y)) = point.xy() {
+        bytes[..32].copy_from_slice(&x.into_bigint().to_bytes_be());
+        bytes[32..].copy_from_slice(&y.into_bigint().to_bytes_be());
+    }
+    bytes
+}
@@ -0,0 +1,152 @@
+use anchor_lang::solana_program::hash::hashv;
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+
+use super::{
+    backend::{g1_add, g1_mul, pairing_check},
+    Groth16DynVerifier, Groth16Error, Groth16Verifyingkey, PreparedGroth16Verifier,
+};
+
+/// Domain separator for the Fiat-Shamir transcript of a batch.
+pub const BATCH_TRANSCRIPT_DOMAIN: &[u8] = b"zk-factor:groth16-batch:v1";
+
+/// Verifies many proofs against the same verifying key with a single
+/// multi-pairing of `N + 3` pairs.
+///
+/// Every proof is weighted by a 128-bit scalar `r_i` derived from the proof
+/// bytes, then checked as:
+///
+/// `prod e(r_i * -A_i, B_i) * e(sum r_i * PI_i, gamma) * e(sum r_i * C_i, delta)
+///     * e((sum r_i) * alpha, beta) == 1`
+#[derive(PartialEq, Eq, Debug)]
+pub struct Groth16BatchVerifier<'a, const CHECK: bool = true> {
+    proofs: Vec<PreparedGroth16Verifier<'a>>,
+    verifyingkey: &'a Groth16Verifyingkey<'a>,
+}
+
+/// Batch verifier that does not check that public inputs are smaller than
+/// field size.
+pub type UncheckedGroth16BatchVerifier<'a> = Groth16BatchVerifier<'a, false>;
+
+impl<'a, const CHECK: bool> Groth16BatchVerifier<'a, CHECK> {
+    pub fn new(verifyingkey: &'a Groth16Verifyingkey<'a>) -> Groth16BatchVerifier<'a, CHECK> {
+        Groth16BatchVerifier {
+            proofs: Vec::new(),
+            verifyingkey,
+        }
+    }
+
+    pub fn with_capacity(
+        verifyingkey: &'a Groth16Verifyingkey<'a>,
+        capacity: usize,
+    ) -> Groth16BatchVerifier<'a, CHECK> {
+        Groth16BatchVerifier {
+            proofs: Vec::with_capacity(capacity),
+            verifyingkey,
+        }
+    }
+
+    /// Prepares the public inputs of a proof and adds it to the batch.
+    pub fn push(
+        &mut self,
+        proof_a: &'a [u8; 64],
+        proof_b: &'a [u8; 128],
+        proof_c: &'a [u8; 64],
+        public_inputs: &'a [[u8; 32]],
+    ) -> Result<(), Groth16Error> {
+        let prepared = Groth16DynVerifier::<'a, CHECK>::new(
+            proof_a,
+            proof_b,
+            proof_c,
+            public_inputs,
+            self.verifyingkey,
+        )?
+        .prepare_inputs()?;
+
+        self.proofs.push(prepared);
+
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.proofs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.proofs.is_empty()
+    }
+
+    /// Runs the combined pairing check. Fails if any proof in the batch is
+    /// invalid, without telling which one.
+    pub fn verify(&self) -> Result<bool, Groth16Error> {
+        if self.proofs.is_empty() {
+            return Err(Groth16Error::InvalidBatchSize);
+        }
+
+        let scalars = self.challenge_scalars();
+
+        let mut pairing_input = Vec::with_capacity((self.proofs.len() + 3) * 192);
+        let mut acc_inputs = [0u8; 64];
+        let mut acc_c = [0u8; 64];
+        let mut scalar_sum = BigUint::default();
+
+        for (proof, scalar) in self.proofs.iter().zip(scalars.iter()) {
+            pairing_input.extend_from_slice(&g1_mul(&proof.proof_a, scalar)?);
+            pairing_input.extend_from_slice(&proof.proof_b);
+
+            acc_inputs = g1_add(&acc_inputs, &g1_mul(&proof.prepared_public_inputs, scalar)?)?;
+            acc_c = g1_add(&acc_c, &g1_mul(&proof.proof_c, scalar)?)?;
+            scalar_sum += BigUint::from_bytes_be(scalar);
+        }
+
+        let alpha = g1_mul(
+            &self.verifyingkey.vk_alpha_g1,
+            &scalar_to_be_bytes(scalar_sum % BigUint::from(ark_bn254::Fr::MODULUS)),
+        )?;
+
+        pairing_input.extend_from_slice(&acc_inputs);
+        pairing_input.extend_from_slice(&self.verifyingkey.vk_gamme_g2);
+        pairing_input.extend_from_slice(&acc_c);
+        pairing_input.extend_from_slice(&self.verifyingkey.vk_delta_g2);
+        pairing_input.extend_from_slice(&alpha);
+        pairing_input.extend_from_slice(&self.verifyingkey.vk_beta_g2);
+
+        let paired = pairing_check(&pairing_input)?;
+
+        if !paired {
+            return Err(Groth16Error::ProofVerificationFailed);
+        }
+        Ok(true)
+    }
+
+    /// Derives one 128-bit scalar per proof from a transcript of every proof
+    /// and its prepared public inputs, so a submitter cannot pick proofs that
+    /// cancel each other out.
+    fn challenge_scalars(&self) -> Vec<[u8; 32]> {
+        let mut transcript: Vec<&[u8]> = Vec::with_capacity(1 + self.proofs.len() * 4);
+        transcript.push(BATCH_TRANSCRIPT_DOMAIN);
+        for proof in self.proofs.iter() {
+            transcript.push(&proof.proof_a);
+            transcript.push(&proof.proof_b);
+            transcript.push(&proof.proof_c);
+            transcript.push(&proof.prepared_public_inputs);
+        }
+        let seed = hashv(&transcript).to_bytes();
+
+        (0..self.proofs.len() as u32)
+            .map(|i| {
+                let digest = hashv(&[&seed, &i.to_le_bytes()]).to_bytes();
+                let mut scalar = [0u8; 32];
+                scalar[16..].copy_from_slice(&digest[..16]);
+                scalar
+            })
+            .collect()
+    }
+}
+
+fn scalar_to_be_bytes(scalar: BigUint) -> [u8; 32] {
+    let bytes = scalar.to_bytes_be();
+    let mut out = [0u8; 32];
+    out[32 - bytes.len()..].copy_from_slice(&bytes);
+    out
+}
@@ -22,4 +22,26 @@ pub enum Groth16Error {
     DecompressingG2Failed,
     #[msg("PublicInputGreaterThenFieldSize")]
     PublicInputGreaterThenFieldSize,
+    #[msg("InvalidBatchSize")]
+    InvalidBatchSize,
+    #[msg("VerifyingKeyInvalidNrPublicInputs")]
+    VerifyingKeyInvalidNrPublicInputs,
+    #[msg("VerifyingKeyCoordinateNotInField")]
+    VerifyingKeyCoordinateNotInField,
+    #[msg("VerifyingKeyPointNotOnCurve")]
+    VerifyingKeyPointNotOnCurve,
+    #[msg("VerifyingKeyPointNotInSubgroup")]
+    VerifyingKeyPointNotInSubgroup,
+    #[msg("VerifyingKeyPointAtInfinity")]
+    VerifyingKeyPointAtInfinity,
+    #[msg("VerifyingKeyGammaEqualsDelta")]
+    VerifyingKeyGammaEqualsDelta,
+    #[msg("ProofCoordinateNotInField")]
+    ProofCoordinateNotInField,
+    #[msg("ProofPointNotOnCurve")]
+    ProofPointNotOnCurve,
+    #[msg("ProofPointNotInSubgroup")]
+    ProofPointNotInSubgroup,
+    #[msg("ProofPointAtInfinity")]
+    ProofPointAtInfinity,
 }
@@ -1,8 +1,8 @@
 use ark_ff::PrimeField;
 use num_bigint::BigUint;
-use solana_bn254::prelude::*;
+use solana_bn254::compression::prelude::{alt_bn128_g1_decompress, alt_bn128_g2_decompress};
 
-use super::Groth16Error;
+use super::{backend, check_proof, Groth16Error};
 
 #[derive(PartialEq, Eq, Debug)]
 pub struct Groth16Verifyingkey<'a> {
@@ -14,24 +14,80 @@ pub struct Groth16Verifyingkey<'a> {
     pub vk_ic: &'a [[u8; 64]],
 }
 
+/// Unprepared verifier. `CHECK` selects whether public inputs are checked to
+/// be smaller than the field size when they get prepared.
 #[derive(PartialEq, Eq, Debug)]
-pub struct Groth16Verifier<'a, const NR_INPUTS: usize> {
-    proof_a: &'a [u8; 64],
-    proof_b: &'a [u8; 128],
-    proof_c: &'a [u8; 64],
-    public_inputs: &'a [[u8; 32]; NR_INPUTS],
-    prepared_public_inputs: [u8; 64],
-    verifyingkey: &'a Groth16Verifyingkey<'a>,
+pub struct Groth16Verifier<'a, const NR_INPUTS: usize, const CHECK: bool = true> {
+    inner: Groth16DynVerifier<'a, CHECK>,
 }
 
-impl<const NR_INPUTS: usize> Groth16Verifier<'_, NR_INPUTS> {
-    pub fn new<'a>(
-        proof_a: &'a [u8; 64],
-        proof_b: &'a [u8; 128],
-        proof_c: &'a [u8; 64],
+/// Verifier that does not check that public inputs are smaller than field
+/// size.
+pub type UncheckedGroth16Verifier<'a, const NR_INPUTS: usize> =
+    Groth16Verifier<'a, NR_INPUTS, false>;
+
+impl<'a, const NR_INPUTS: usize, const CHECK: bool> Groth16Verifier<'a, NR_INPUTS, CHECK> {
+    pub fn new(
+        proof_a: &[u8; 64],
+        proof_b: &[u8; 128],
+        proof_c: &[u8; 64],
+        public_inputs: &'a [[u8; 32]; NR_INPUTS],
+        verifyingkey: &'a Groth16Verifyingkey<'a>,
+    ) -> Result<Groth16Verifier<'a, NR_INPUTS, CHECK>, Groth16Error> {
+        Ok(Groth16Verifier {
+            inner: Groth16DynVerifier::new(proof_a, proof_b, proof_c, public_inputs, verifyingkey)?,
+        })
+    }
+
+    /// Same as [`Groth16Verifier::new`], but takes 32-byte compressed G1 and
+    /// 64-byte compressed G2 points and decompresses them first.
+    pub fn new_compressed(
+        proof_a: &[u8; 32],
+        proof_b: &[u8; 64],
+        proof_c: &[u8; 32],
         public_inputs: &'a [[u8; 32]; NR_INPUTS],
         verifyingkey: &'a Groth16Verifyingkey<'a>,
-    ) -> Result<Groth16Verifier<'a, NR_INPUTS>, Groth16Error> {
+    ) -> Result<Groth16Verifier<'a, NR_INPUTS, CHECK>, Groth16Error> {
+        Ok(Groth16Verifier {
+            inner: Groth16DynVerifier::new_compressed(
+                proof_a,
+                proof_b,
+                proof_c,
+                public_inputs,
+                verifyingkey,
+            )?,
+        })
+    }
+
+    #[inline(always)]
+    pub fn prepare_inputs(self) -> Result<PreparedGroth16Verifier<'a>, Groth16Error> {
+        self.inner.prepare_inputs()
+    }
+}
+
+/// Same as [`Groth16Verifier`], but the number of public inputs is only known
+/// at runtime and checked against the verifying key.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Groth16DynVerifier<'a, const CHECK: bool = true> {
+    proof_a: [u8; 64],
+    proof_b: [u8; 128],
+    proof_c: [u8; 64],
+    public_inputs: &'a [[u8; 32]],
+    verifyingkey: &'a Groth16Verifyingkey<'a>,
+}
+
+/// Runtime-sized verifier that does not check that public inputs are smaller
+/// than field size.
+pub type UncheckedGroth16DynVerifier<'a> = Groth16DynVerifier<'a, false>;
+
+impl<'a, const CHECK: bool> Groth16DynVerifier<'a, CHECK> {
+    pub fn new(
+        proof_a: &[u8; 64],
+        proof_b: &[u8; 128],
+        proof_c: &[u8; 64],
+        public_inputs: &'a [[u8; 32]],
+        verifyingkey: &'a Groth16Verifyingkey<'a>,
+    ) -> Result<Groth16DynVerifier<'a, CHECK>, Groth16Error> {
         if proof_a.len() != 64 {
             return Err(Groth16Error::InvalidG1Length);
         }
@@ -45,57 +101,93 @@ impl<const NR_INPUTS: usize> Groth16Verifier<'_, NR_INPUTS> {
         }
 
         if public_inputs.len() + 1 != verifyingkey.vk_ic.len() {
-            return Err(Groth16Error::InvalidPublicInputsLength);
+            return Err(Groth16Error::IncompatibleVerifyingKeyWithNrPublicInputs);
         }
 
-        Ok(Groth16Verifier {
-            proof_a,
-            proof_b,
-            proof_c,
+        check_proof(proof_a, proof_b, proof_c)?;
+
+        Ok(Groth16DynVerifier {
+            proof_a: *proof_a,
+            proof_b: *proof_b,
+            proof_c: *proof_c,
             public_inputs,
-            prepared_public_inputs: [0u8; 64],
             verifyingkey,
         })
     }
 
-    pub fn prepare_inputs<const CHECK: bool>(&mut self) -> Result<(), Groth16Error> {
-        let mut prepared_public_inputs = self.verifyingkey.vk_ic[0];
-
-        for (i, input) in self.public_inputs.iter().enumerate() {
-            if CHECK && !is_less_than_bn254_field_size_be(input) {
-                return Err(Groth16Error::PublicInputGreaterThenFieldSize);
-            }
-            let mul_res = alt_bn128_multiplication(
-                &[&self.verifyingkey.vk_ic[i + 1][..], &input[..]].concat(),
-            )
-            .map_err(|_| Groth16Error::PreparingInputsG1MulFailed)?;
-            prepared_public_inputs =
-                alt_bn128_addition(&[&mul_res[..], &prepared_public_inputs[..]].concat())
-                    .map_err(|_| Groth16Error::PreparingInputsG1AdditionFailed)?[..]
-                    .try_into()
-                    .map_err(|_| Groth16Error::PreparingInputsG1AdditionFailed)?;
-        }
-
-        self.prepared_public_inputs = prepared_public_inputs;
+    /// Same as [`Groth16DynVerifier::new`], but takes 32-byte compressed G1
+    /// and 64-byte compressed G2 points and decompresses them first.
+    pub fn new_compressed(
+        proof_a: &[u8; 32],
+        proof_b: &[u8; 64],
+        proof_c: &[u8; 32],
+        public_inputs: &'a [[u8; 32]],
+        verifyingkey: &'a Groth16Verifyingkey<'a>,
+    ) -> Result<Groth16DynVerifier<'a, CHECK>, Groth16Error> {
+        let proof_a = decompress_g1(proof_a)?;
+        let proof_b = decompress_g2(proof_b)?;
+        let proof_c = decompress_g1(proof_c)?;
 
-        Ok(())
+        Self::new(&proof_a, &proof_b, &proof_c, public_inputs, verifyingkey)
     }
 
-    /// Verifies the proof, and checks that public inputs are smaller than
-    /// field size.
-    pub fn verify(&mut self) -> Result<bool, Groth16Error> {
-        self.verify_common::<true>()
+    /// Folds the public inputs into a single G1 point, consuming the verifier.
+    /// Only the returned [`PreparedGroth16Verifier`] can run the pairing.
+    pub fn prepare_inputs(self) -> Result<PreparedGroth16Verifier<'a>, Groth16Error> {
+        let prepared_public_inputs = fold_public_inputs(
+            &self.verifyingkey.vk_ic[0],
+            &self.verifyingkey.vk_ic[1..],
+            self.public_inputs,
+            CHECK,
+        )?;
+
+        Ok(PreparedGroth16Verifier {
+            proof_a: self.proof_a,
+            proof_b: self.proof_b,
+            proof_c: self.proof_c,
+            prepared_public_inputs,
+            verifyingkey: self.verifyingkey,
+        })
     }
+}
+
+/// Verifier whose public inputs have already been prepared.
+#[derive(PartialEq, Eq, Debug)]
+pub struct PreparedGroth16Verifier<'a> {
+    pub(crate) proof_a: [u8; 64],
+    pub(crate) proof_b: [u8; 128],
+    pub(crate) proof_c: [u8; 64],
+    pub(crate) prepared_public_inputs: [u8; 64],
+    verifyingkey: &'a Groth16Verifyingkey<'a>,
+}
 
-    /// Verifies the proof, and does not check that public inputs are smaller
-    /// than field size.
-    pub fn verify_unchecked(&mut self) -> Result<bool, Groth16Error> {
-        self.verify_common::<false>()
+impl<'a> PreparedGroth16Verifier<'a> {
+    /// Resumes a verification whose public inputs were already folded with
+    /// [`fold_public_inputs`], for instance across several instructions.
+    pub fn from_prepared_inputs(
+        proof_a: &[u8; 64],
+        proof_b: &[u8; 128],
+        proof_c: &[u8; 64],
+        prepared_public_inputs: &[u8; 64],
+        verifyingkey: &'a Groth16Verifyingkey<'a>,
+    ) -> Result<PreparedGroth16Verifier<'a>, Groth16Error> {
+        check_proof(proof_a, proof_b, proof_c)?;
+
+        Ok(PreparedGroth16Verifier {
+            proof_a: *proof_a,
+            proof_b: *proof_b,
+            proof_c: *proof_c,
+            prepared_public_inputs: *prepared_public_inputs,
+            verifyingkey,
+        })
     }
 
-    fn verify_common<const CHECK: bool>(&mut self) -> Result<bool, Groth16Error> {
-        self.prepare_inputs::<CHECK>()?;
+    pub fn prepared_public_inputs(&self) -> &[u8; 64] {
+        &self.prepared_public_inputs
+    }
 
+    /// Runs the pairing check against the prepared public inputs.
+    pub fn verify(&self) -> Result<bool, Groth16Error> {
         let pairing_input = [
             self.proof_a.as_slice(),
             self.proof_b.as_slice(),
@@ -108,16 +200,49 @@ impl<const NR_INPUTS: usize> Groth16Verifier<'_, NR_INPUTS> {
         ]
         .concat();
 
-        let pairing_res = alt_bn128_pairing(pairing_input.as_slice())
-            .map_err(|_| Groth16Error::ProofVerificationFailed)?;
+        let paired = backend::pairing_check(&pairing_input)?;
 
-        if pairing_res[31] != 1 {
+        if !paired {
             return Err(Groth16Error::ProofVerificationFailed);
         }
         Ok(true)
     }
 }
 
+/// Adds `sum ic[i] * public_inputs[i]` to `acc`, `ic` being the IC points
+/// matching `public_inputs`, without `vk_ic[0]`.
+///
+/// Starting from `vk_ic[0]` with every input gives the prepared public
+/// inputs; the inputs can also be folded in chunks.
+pub fn fold_public_inputs(
+    acc: &[u8; 64],
+    ic: &[[u8; 64]],
+    public_inputs: &[[u8; 32]],
+    check: bool,
+) -> Result<[u8; 64], Groth16Error> {
+    if ic.len() != public_inputs.len() {
+        return Err(Groth16Error::IncompatibleVerifyingKeyWithNrPublicInputs);
+    }
+
+    let mut acc = *acc;
+    for (ic, input) in ic.iter().zip(public_inputs.iter()) {
+        if check && !is_less_than_bn254_field_size_be(input) {
+            return Err(Groth16Error::PublicInputGreaterThenFieldSize);
+        }
+        acc = backend::g1_add(&backend::g1_mul(ic, input)?, &acc)?;
+    }
+
+    Ok(acc)
+}
+
+pub fn decompress_g1(g1_bytes: &[u8; 32]) -> Result<[u8; 64], Groth16Error> {
+    alt_bn128_g1_decompress(g1_bytes).map_err(|_| Groth16Error::DecompressingG1Failed)
+}
+
+pub fn decompress_g2(g2_bytes: &[u8; 64]) -> Result<[u8; 128], Groth16Error> {
+    alt_bn128_g2_decompress(g2_bytes).map_err(|_| Groth16Error::DecompressingG2Failed)
+}
+
 pub fn is_less_than_bn254_field_size_be(bytes: &[u8; 32]) -> bool {
     let bigint = BigUint::from_bytes_be(bytes);
     bigint < ark_bn254::Fr::MODULUS.into()
@@ -1,5 +1,19 @@
+#[cfg(feature = "ark-backend")]
+pub mod arkworks;
+pub mod batch;
 pub mod errors;
 pub mod lib;
+pub mod syscalls;
+pub mod validation;
 
+pub use batch::*;
 pub use errors::*;
 pub use lib::*;
+pub use validation::*;
+
+/// BN254 operations used by the verifiers, the `alt_bn128` syscalls unless
+/// the `ark-backend` feature is enabled.
+#[cfg(feature = "ark-backend")]
+pub(crate) use arkworks as backend;
+#[cfg(not(feature = "ark-backend"))]
+pub(crate) use syscalls as backend;
@@ -0,0 +1,36 @@
+//! BN254 operations backed by the `alt_bn128` syscalls, the default backend.
+
+use solana_bn254::prelude::*;
+
+use super::Groth16Error;
+
+/// `point * scalar`, both big-endian.
+pub fn g1_mul(point: &[u8; 64], scalar: &[u8; 32]) -> Result<[u8; 64], Groth16Error> {
+    alt_bn128_multiplication(&[&point[..], &scalar[..]].concat())
+        .map_err(|_| Groth16Error::PreparingInputsG1MulFailed)?[..]
+        .try_into()
+        .map_err(|_| Groth16Error::PreparingInputsG1MulFailed)
+}
+
+/// `left + right`, both big-endian.
+pub fn g1_add(left: &[u8; 64], right: &[u8; 64]) -> Result<[u8; 64], Groth16Error> {
+    alt_bn128_addition(&[&left[..], &right[..]].concat())
+        .map_err(|_| Groth16Error::PreparingInputsG1AdditionFailed)?[..]
+        .try_into()
+        .map_err(|_| Groth16Error::PreparingInputsG1AdditionFailed)
+}
+
+/// Whether the product of the pairings of the big-endian `G1 || G2` pairs in
+/// `input` is one.
+pub fn pairing_check(input: &[u8]) -> Result<bool, Groth16Error> {
+    let pairing_res =
+        alt_bn128_pairing(input).map_err(|_| Groth16Error::ProofVerificationFailed)?;
+
+    Ok(pairing_res[31] == 1)
+}
+
+/// Whether a big-endian G2 point is in the prime order subgroup, which the
+/// pairing syscall checks for every G2 point it is given.
+pub fn g2_in_subgroup(point: &[u8; 128]) -> bool {
+    alt_bn128_pairing(&[&[0u8; 64][..], &point[..]].concat()).is_ok()
+}
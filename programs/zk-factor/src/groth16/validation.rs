@@ -0,0 +1,155 @@
+use ark_bn254::{Fq, Fq2, G1Affine, G2Affine};
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+
+use super::{backend, Groth16Error, Groth16Verifyingkey};
+
+/// Why a big-endian encoded point was rejected. Callers map it to the
+/// [`Groth16Error`] variant matching what the point is (VK or proof).
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PointError {
+    CoordinateNotInField,
+    NotOnCurve,
+    NotInSubgroup,
+    Identity,
+}
+
+impl Groth16Verifyingkey<'_> {
+    /// Checks that the verifying key is well formed before trusting it:
+    /// the number of public inputs matches the IC points, every point is on
+    /// the curve and in the right subgroup, and gamma differs from delta.
+    ///
+    /// This is too expensive to run on every verification, it is meant for
+    /// the host or for a one-off check when a key is registered. The G2
+    /// subgroup checks go through the backend pairing, which on chain is far
+    /// cheaper than the arkworks scalar multiplication.
+    pub fn validate(&self) -> Result<(), Groth16Error> {
+        if self.vk_ic.is_empty() || self.nr_pubinputs != self.vk_ic.len() - 1 {
+            return Err(Groth16Error::VerifyingKeyInvalidNrPublicInputs);
+        }
+
+        check_g1(&self.vk_alpha_g1).map_err(vk_point_error)?;
+        check_g2_subgroup(&self.vk_beta_g2).map_err(vk_point_error)?;
+        check_g2_subgroup(&self.vk_gamme_g2).map_err(vk_point_error)?;
+        check_g2_subgroup(&self.vk_delta_g2).map_err(vk_point_error)?;
+        for ic in self.vk_ic.iter() {
+            check_g1(ic).map_err(vk_point_error)?;
+        }
+
+        if self.vk_gamme_g2 == self.vk_delta_g2 {
+            return Err(Groth16Error::VerifyingKeyGammaEqualsDelta);
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks the proof points before they reach the pairing: coordinates in the
+/// field, points on the curve, in the right subgroup and not the identity.
+///
+/// The G2 subgroup check of `B` goes through the backend pairing, like the
+/// verifying key ones, so that a malformed proof is rejected for what it is
+/// rather than as a failed pairing.
+pub fn check_proof(
+    proof_a: &[u8; 64],
+    proof_b: &[u8; 128],
+    proof_c: &[u8; 64],
+) -> Result<(), Groth16Error> {
+    check_g1(proof_a).map_err(proof_point_error)?;
+    check_g2_subgroup(proof_b).map_err(proof_point_error)?;
+    check_g1(proof_c).map_err(proof_point_error)?;
+
+    Ok(())
+}
+
+/// On the curve and in the subgroup, through the backend pairing.
+fn check_g2_subgroup(bytes: &[u8; 128]) -> Result<(), PointError> {
+    check_g2_on_curve(bytes)?;
+    if !backend::g2_in_subgroup(bytes) {
+        return Err(PointError::NotInSubgroup);
+    }
+
+    Ok(())
+}
+
+fn proof_point_error(err: PointError) -> Groth16Error {
+    match err {
+        PointError::CoordinateNotInField => Groth16Error::ProofCoordinateNotInField,
+        PointError::NotOnCurve => Groth16Error::ProofPointNotOnCurve,
+        PointError::NotInSubgroup => Groth16Error::ProofPointNotInSubgroup,
+        PointError::Identity => Groth16Error::ProofPointAtInfinity,
+    }
+}
+
+fn vk_point_error(err: PointError) -> Groth16Error {
+    match err {
+        PointError::CoordinateNotInField => Groth16Error::VerifyingKeyCoordinateNotInField,
+        PointError::NotOnCurve => Groth16Error::VerifyingKeyPointNotOnCurve,
+        PointError::NotInSubgroup => Groth16Error::VerifyingKeyPointNotInSubgroup,
+        PointError::Identity => Groth16Error::VerifyingKeyPointAtInfinity,
+    }
+}
+
+/// Decodes a big-endian `x || y` G1 point, as passed to the syscalls.
+pub fn decode_g1(bytes: &[u8; 64]) -> Result<G1Affine, PointError> {
+    if bytes.iter().all(|b| *b == 0) {
+        return Err(PointError::Identity);
+    }
+
+    let point = G1Affine::new_unchecked(fq(&bytes[0..32])?, fq(&bytes[32..64])?);
+    if !point.is_on_curve() {
+        return Err(PointError::NotOnCurve);
+    }
+    if !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(PointError::NotInSubgroup);
+    }
+
+    Ok(point)
+}
+
+/// Decodes a big-endian `x_c1 || x_c0 || y_c1 || y_c0` G2 point, as passed to
+/// the syscalls.
+pub fn decode_g2(bytes: &[u8; 128]) -> Result<G2Affine, PointError> {
+    let point = decode_g2_on_curve(bytes)?;
+    if !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(PointError::NotInSubgroup);
+    }
+
+    Ok(point)
+}
+
+/// Same as [`decode_g2`], without the subgroup check.
+fn decode_g2_on_curve(bytes: &[u8; 128]) -> Result<G2Affine, PointError> {
+    if bytes.iter().all(|b| *b == 0) {
+        return Err(PointError::Identity);
+    }
+
+    let x = Fq2::new(fq(&bytes[32..64])?, fq(&bytes[0..32])?);
+    let y = Fq2::new(fq(&bytes[96..128])?, fq(&bytes[64..96])?);
+    let point = G2Affine::new_unchecked(x, y);
+    if !point.is_on_curve() {
+        return Err(PointError::NotOnCurve);
+    }
+
+    Ok(point)
+}
+
+pub fn check_g1(bytes: &[u8; 64]) -> Result<(), PointError> {
+    decode_g1(bytes).map(|_| ())
+}
+
+pub fn check_g2(bytes: &[u8; 128]) -> Result<(), PointError> {
+    decode_g2(bytes).map(|_| ())
+}
+
+pub fn check_g2_on_curve(bytes: &[u8; 128]) -> Result<(), PointError> {
+    decode_g2_on_curve(bytes).map(|_| ())
+}
+
+fn fq(bytes: &[u8]) -> Result<Fq, PointError> {
+    let bigint = BigUint::from_bytes_be(bytes);
+    if bigint >= Fq::MODULUS.into() {
+        return Err(PointError::CoordinateNotInField);
+    }
+    Ok(bigint.into())
+}
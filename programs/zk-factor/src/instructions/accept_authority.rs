@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::{ZkFactorConfig, ZkFactorError, CONFIG_SEED};
+
+pub fn _accept_authority(ctx: &mut Context<AcceptAuthorityAccounts>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.authority = config.pending_authority.take();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthorityAccounts<'info> {
+    pub pending_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.pending_authority == Some(pending_authority.key()) @ ZkFactorError::Unauthorized,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+}
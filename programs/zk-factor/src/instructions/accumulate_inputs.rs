@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{Circuit, VerificationSession, SESSION_SEED};
+
+/// Folds the next chunk of public inputs into the session, as many as fit in
+/// the compute budget of an instruction.
+pub fn _accumulate_inputs(
+    ctx: &mut Context<AccumulateInputsAccounts>,
+    public_inputs: Vec<[u8; 32]>,
+) -> Result<()> {
+    let circuit = ctx.accounts.circuit.load()?;
+
+    ctx.accounts.session.accumulate(&circuit, &public_inputs)
+}
+
+#[derive(Accounts)]
+pub struct AccumulateInputsAccounts<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        address = session.circuit,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        mut,
+        seeds = [SESSION_SEED, circuit.key().as_ref(), owner.key().as_ref()],
+        bump = session.bump,
+    )]
+    pub session: Account<'info, VerificationSession>,
+}
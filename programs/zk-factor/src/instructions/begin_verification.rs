@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    Circuit, VerificationSession, ZkFactorConfig, ZkFactorError, CIRCUIT_SEED, CONFIG_SEED,
+    DISCRIMINATOR, SESSION_SEED,
+};
+
+/// Opens a [`VerificationSession`] for a proof against `circuit_id`, followed
+/// by `accumulate_inputs` and `finalize_verification`, or
+/// `cancel_verification`.
+pub fn _begin_verification(
+    ctx: &mut Context<BeginVerificationAccounts>,
+    _circuit_id: [u8; 32],
+) -> Result<()> {
+    let circuit = ctx.accounts.circuit.load()?;
+    require!(circuit.is_enabled(), ZkFactorError::CircuitDisabled);
+
+    ctx.accounts.session.set_inner(VerificationSession {
+        owner: ctx.accounts.owner.key(),
+        circuit: ctx.accounts.circuit.key(),
+        circuit_version: circuit.version,
+        nr_pubinputs: circuit.nr_pubinputs,
+        next_input: 0,
+        prepared_public_inputs: circuit.vk_ic[0],
+        inputs_hash: [0u8; 32],
+        bump: ctx.bumps.session,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(circuit_id: [u8; 32])]
+pub struct BeginVerificationAccounts<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), circuit_id.as_ref()],
+        bump = circuit.load()?.bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = DISCRIMINATOR + VerificationSession::INIT_SPACE,
+        seeds = [SESSION_SEED, circuit.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub session: Account<'info, VerificationSession>,
+
+    // Program accounts
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::{VerificationSession, SESSION_SEED};
+
+/// Closes an unfinished [`VerificationSession`], so the owner can start over
+/// after folding wrong inputs, a circuit update or a failed proof.
+pub fn _cancel_verification(_ctx: &mut Context<CancelVerificationAccounts>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelVerificationAccounts<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [SESSION_SEED, session.circuit.as_ref(), owner.key().as_ref()],
+        bump = session.bump,
+    )]
+    pub session: Account<'info, VerificationSession>,
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::{ProofBuffer, BUFFER_SEED};
+
+/// Closes an unverified [`ProofBuffer`], so the owner can start over after
+/// writing a wrong chunk or a circuit update.
+pub fn _close_buffer(_ctx: &mut Context<CloseBufferAccounts>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseBufferAccounts<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [BUFFER_SEED, buffer.load()?.circuit.as_ref(), owner.key().as_ref()],
+        bump = buffer.load()?.bump,
+    )]
+    pub buffer: AccountLoader<'info, ProofBuffer>,
+}
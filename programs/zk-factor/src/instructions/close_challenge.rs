@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{Challenge, ZkFactorError, CHALLENGE_SEED};
+
+/// Closes a solved challenge, lamport or token, and returns its rent to the
+/// poster. The modulus can then be posted again.
+pub fn _close_challenge(_ctx: &mut Context<CloseChallengeAccounts>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseChallengeAccounts<'info> {
+    #[account(mut)]
+    pub poster: Signer<'info>,
+
+    #[account(
+        mut,
+        close = poster,
+        seeds = [
+            CHALLENGE_SEED,
+            challenge.config.as_ref(),
+            poster.key().as_ref(),
+            &Challenge::modulus_hash(&challenge.modulus),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.is_solved() @ ZkFactorError::ChallengeNotSolved,
+    )]
+    pub challenge: Account<'info, Challenge>,
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{statement_hash, ProofReceipt, ZkFactorError, RECEIPT_SEED};
+
+/// Closes an expired receipt, the rent goes back to the prover. Anyone can
+/// call it.
+pub fn _close_receipt(ctx: &mut Context<CloseReceiptAccounts>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.receipt.is_expired(now),
+        ZkFactorError::ReceiptNotExpired
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseReceiptAccounts<'info> {
+    /// CHECK: receives the rent, checked against the receipt.
+    #[account(
+        mut,
+        address = receipt.prover,
+    )]
+    pub prover: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = prover,
+        seeds = [
+            RECEIPT_SEED,
+            receipt.circuit.as_ref(),
+            receipt.prover.as_ref(),
+            &statement_hash(&receipt.public_inputs),
+        ],
+        bump = receipt.bump,
+    )]
+    pub receipt: Account<'info, ProofReceipt>,
+}
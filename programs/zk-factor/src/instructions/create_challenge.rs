@@ -0,0 +1,89 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{
+    Challenge, Circuit, ZkFactorConfig, ZkFactorError, CHALLENGE_SEED, CIRCUIT_SEED, CONFIG_SEED,
+    DISCRIMINATOR, FACTOR_LIMBS_CIRCUIT_ID,
+};
+
+/// Posts a modulus, as 64-bit limbs, to factor until `deadline`, escrowing
+/// `reward` lamports in the challenge.
+pub fn _create_challenge(
+    ctx: &mut Context<CreateChallengeAccounts>,
+    modulus: Vec<u64>,
+    reward: u64,
+    deadline: i64,
+) -> Result<()> {
+    let circuit_version = {
+        let circuit = ctx.accounts.circuit.load()?;
+        Challenge::check_modulus(&modulus, &circuit)?;
+        circuit.version
+    };
+    require!(reward > 0, ZkFactorError::InvalidReward);
+    require!(
+        deadline > Clock::get()?.unix_timestamp,
+        ZkFactorError::InvalidDeadline
+    );
+
+    ctx.accounts.challenge.set_inner(Challenge {
+        config: ctx.accounts.config.key(),
+        poster: ctx.accounts.poster.key(),
+        circuit: ctx.accounts.circuit.key(),
+        circuit_version,
+        modulus,
+        reward,
+        mint: None,
+        deadline,
+        solver: None,
+        bump: ctx.bumps.challenge,
+    });
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.poster.to_account_info(),
+                to: ctx.accounts.challenge.to_account_info(),
+            },
+        ),
+        reward,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(modulus: Vec<u64>)]
+pub struct CreateChallengeAccounts<'info> {
+    #[account(mut)]
+    pub poster: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), FACTOR_LIMBS_CIRCUIT_ID.as_ref()],
+        bump = circuit.load()?.bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        init,
+        payer = poster,
+        space = DISCRIMINATOR + Challenge::INIT_SPACE,
+        seeds = [
+            CHALLENGE_SEED,
+            config.key().as_ref(),
+            poster.key().as_ref(),
+            &Challenge::modulus_hash(&modulus),
+        ],
+        bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    // Program accounts
+    pub system_program: Program<'info, System>,
+}
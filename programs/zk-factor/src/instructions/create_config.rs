@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::{
+    Treasury, ZkFactorConfig, CONFIG_SEED, DISCRIMINATOR, LP_DECIMALS, LP_SEED, TREASURY_SEED,
+};
+
+/// Creates the config `seed`, with the signer as authority, its treasury and
+/// the LP mint of its `mint_x`/`mint_y` pool. `verification_fee` is in
+/// lamports per proof.
+pub fn _create_config(
+    ctx: &mut Context<CreateConfigAccounts>,
+    seed: u64,
+    fee: u16,
+    swap_fee_bps: u16,
+    verification_fee: u64,
+) -> Result<()> {
+    ctx.accounts.config.set_inner(ZkFactorConfig {
+        authority: Some(ctx.accounts.authority.key()),
+        pending_authority: None,
+        seed,
+        fee: 0,
+        swap_fee_bps: 0,
+        verification_fee,
+        locked: false,
+        mint_x: ctx.accounts.mint_x.key(),
+        mint_y: ctx.accounts.mint_y.key(),
+        allowlist_root: [0u8; 32],
+        lp_bump: ctx.bumps.mint_lp,
+        bump: ctx.bumps.config,
+    });
+    ctx.accounts.treasury.set_inner(Treasury {
+        config: ctx.accounts.config.key(),
+        bump: ctx.bumps.treasury,
+    });
+    ctx.accounts.config.set_fee(fee)?;
+    ctx.accounts.config.set_swap_fee(swap_fee_bps)
+}
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct CreateConfigAccounts<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = DISCRIMINATOR + ZkFactorConfig::INIT_SPACE,
+        seeds = [CONFIG_SEED, seed.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint_x: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint_y: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [LP_SEED, config.key().as_ref()],
+        bump,
+        mint::decimals = LP_DECIMALS,
+        mint::authority = config,
+        mint::token_program = token_program,
+    )]
+    pub mint_lp: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = DISCRIMINATOR + Treasury::INIT_SPACE,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    // Program accounts
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
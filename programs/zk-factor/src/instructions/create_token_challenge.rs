@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    Challenge, Circuit, ZkFactorConfig, ZkFactorError, CHALLENGE_SEED, CIRCUIT_SEED, CONFIG_SEED,
+    DISCRIMINATOR, FACTOR_LIMBS_CIRCUIT_ID,
+};
+
+/// Posts a modulus, as 64-bit limbs, to factor until `deadline`, escrowing
+/// `reward` `mint_x` tokens in the challenge vault.
+pub fn _create_token_challenge(
+    ctx: &mut Context<CreateTokenChallengeAccounts>,
+    modulus: Vec<u64>,
+    reward: u64,
+    deadline: i64,
+) -> Result<()> {
+    let circuit_version = {
+        let circuit = ctx.accounts.circuit.load()?;
+        Challenge::check_modulus(&modulus, &circuit)?;
+        circuit.version
+    };
+    require!(reward > 0, ZkFactorError::InvalidReward);
+    require!(
+        deadline > Clock::get()?.unix_timestamp,
+        ZkFactorError::InvalidDeadline
+    );
+
+    ctx.accounts.challenge.set_inner(Challenge {
+        config: ctx.accounts.config.key(),
+        poster: ctx.accounts.poster.key(),
+        circuit: ctx.accounts.circuit.key(),
+        circuit_version,
+        modulus,
+        reward,
+        mint: Some(ctx.accounts.mint.key()),
+        deadline,
+        solver: None,
+        bump: ctx.bumps.challenge,
+    });
+
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.poster_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.poster.to_account_info(),
+            },
+        ),
+        reward,
+        ctx.accounts.mint.decimals,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(modulus: Vec<u64>)]
+pub struct CreateTokenChallengeAccounts<'info> {
+    #[account(mut)]
+    pub poster: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), FACTOR_LIMBS_CIRCUIT_ID.as_ref()],
+        bump = circuit.load()?.bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        init,
+        payer = poster,
+        space = DISCRIMINATOR + Challenge::INIT_SPACE,
+        seeds = [
+            CHALLENGE_SEED,
+            config.key().as_ref(),
+            poster.key().as_ref(),
+            &Challenge::modulus_hash(&modulus),
+        ],
+        bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        address = config.mint_x,
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = poster,
+        associated_token::token_program = token_program,
+    )]
+    pub poster_ata: InterfaceAccount<'info, TokenAccount>,
+
+    // `init_if_needed`: the vault is a deterministic associated token
+    // account, anyone could create it first to block the challenge.
+    #[account(
+        init_if_needed,
+        payer = poster,
+        associated_token::mint = mint,
+        associated_token::authority = challenge,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    // Program accounts
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
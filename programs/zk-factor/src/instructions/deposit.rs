@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+use crate::{
+    charge_verification_fee, deposit_amounts, first_deposit_lp, Circuit, PoolAccessProof, Treasury,
+    ZkFactorConfig, ZkFactorError, ALLOWLIST_CIRCUIT_ID, CIRCUIT_SEED, CONFIG_SEED, LP_SEED,
+    MINIMUM_LIQUIDITY, TREASURY_SEED,
+};
+
+/// Mints `lp` LP tokens against `mint_x` and `mint_y` in the pool ratio, at
+/// most `max_x` and `max_y`. The first deposit sets the ratio and mints
+/// `sqrt(max_x * max_y)`, `lp` is then ignored. [`MINIMUM_LIQUIDITY`] of it
+/// is locked in the `locked_lp` account of the config, which nothing can
+/// withdraw from.
+pub fn _deposit(ctx: &mut Context<DepositAccounts>, input: DepositInput) -> Result<()> {
+    ctx.accounts.config.verify_pool_access(
+        &*ctx.accounts.circuit.load()?,
+        ctx.accounts.user.key,
+        &input.proof,
+    )?;
+    charge_verification_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.treasury,
+        &ctx.accounts.user,
+        &ctx.accounts.system_program,
+        1,
+    )?;
+
+    let is_first_deposit = ctx.accounts.mint_lp.supply == 0;
+    let (lp, amount_x, amount_y) = match ctx.accounts.mint_lp.supply {
+        0 => (
+            first_deposit_lp(input.max_x, input.max_y).ok_or(ZkFactorError::InvalidAmount)?,
+            input.max_x,
+            input.max_y,
+        ),
+        supply => {
+            let (amount_x, amount_y) = deposit_amounts(
+                input.lp,
+                ctx.accounts.vault_x.amount,
+                ctx.accounts.vault_y.amount,
+                supply,
+            )
+            .ok_or(ZkFactorError::MathOverflow)?;
+            (input.lp, amount_x, amount_y)
+        }
+    };
+    require!(
+        lp > 0 && amount_x > 0 && amount_y > 0,
+        ZkFactorError::InvalidAmount
+    );
+    require!(
+        amount_x <= input.max_x && amount_y <= input.max_y,
+        ZkFactorError::SlippageExceeded
+    );
+
+    ctx.accounts.deposit_token(true, amount_x)?;
+    ctx.accounts.deposit_token(false, amount_y)?;
+    if is_first_deposit {
+        ctx.accounts.mint_lp(true, MINIMUM_LIQUIDITY)?;
+    }
+    ctx.accounts.mint_lp(false, lp)
+}
+
+#[derive(Accounts)]
+pub struct DepositAccounts<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = !config.locked @ ZkFactorError::PoolLocked,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), ALLOWLIST_CIRCUIT_ID.as_ref()],
+        bump = circuit.load()?.bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        address = config.mint_x,
+        mint::token_program = token_program,
+    )]
+    pub mint_x: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        address = config.mint_y,
+        mint::token_program = token_program,
+    )]
+    pub mint_y: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [LP_SEED, config.key().as_ref()],
+        bump = config.lp_bump,
+    )]
+    pub mint_lp: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint_x,
+        associated_token::authority = config,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_x: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint_y,
+        associated_token::authority = config,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_y: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_x,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_x: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_y,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_y: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint_lp,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_lp: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint_lp,
+        associated_token::authority = config,
+        associated_token::token_program = token_program,
+    )]
+    pub locked_lp: InterfaceAccount<'info, TokenAccount>,
+
+    // Program accounts
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl DepositAccounts<'_> {
+    fn deposit_token(&self, is_x: bool, amount: u64) -> Result<()> {
+        let (from, to, mint) = match is_x {
+            true => (&self.user_x, &self.vault_x, &self.mint_x),
+            false => (&self.user_y, &self.vault_y, &self.mint_y),
+        };
+
+        transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: from.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: self.user.to_account_info(),
+                },
+            ),
+            amount,
+            mint.decimals,
+        )
+    }
+
+    fn mint_lp(&self, is_locked: bool, amount: u64) -> Result<()> {
+        let to = match is_locked {
+            true => &self.locked_lp,
+            false => &self.user_lp,
+        };
+        let seed = self.config.seed.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[CONFIG_SEED, seed.as_ref(), &[self.config.bump]]];
+
+        mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: self.mint_lp.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: self.config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DepositInput {
+    pub lp: u64,
+    pub max_x: u64,
+    pub max_y: u64,
+    pub proof: PoolAccessProof,
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    charge_verification_fee, compute_units_since, compute_units_start, Circuit,
+    PreparedGroth16Verifier, ProofVerified, Treasury, VerificationSession, ZkFactorConfig,
+    ZkFactorError, SESSION_SEED, TREASURY_SEED,
+};
+
+/// Runs the pairing against the public inputs folded in the session, and
+/// closes it on success.
+pub fn _finalize_verification(
+    ctx: &mut Context<FinalizeVerificationAccounts>,
+    input: FinalizeVerificationInput,
+) -> Result<()> {
+    let compute_units = compute_units_start();
+    let circuit = ctx.accounts.circuit.load()?;
+    let session = &ctx.accounts.session;
+    require!(circuit.is_enabled(), ZkFactorError::CircuitDisabled);
+    require_eq!(
+        session.circuit_version,
+        circuit.version,
+        ZkFactorError::CircuitUpdated
+    );
+    require!(session.is_complete(), ZkFactorError::SessionIncomplete);
+    let vk = circuit.verifying_key();
+
+    PreparedGroth16Verifier::from_prepared_inputs(
+        &input.proof_a,
+        &input.proof_b,
+        &input.proof_c,
+        &session.prepared_public_inputs,
+        &vk,
+    )?
+    .verify()?;
+    charge_verification_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.treasury,
+        &ctx.accounts.owner,
+        &ctx.accounts.system_program,
+        1,
+    )?;
+
+    emit!(ProofVerified {
+        prover: ctx.accounts.owner.key(),
+        circuit: ctx.accounts.circuit.key(),
+        public_inputs: Vec::new(),
+        inputs_hash: Some(session.inputs_hash),
+        compute_units: compute_units_since(compute_units),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FinalizeVerificationAccounts<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        address = session.circuit,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        address = circuit.load()?.config,
+        constraint = !config.locked @ ZkFactorError::PoolLocked,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [SESSION_SEED, circuit.key().as_ref(), owner.key().as_ref()],
+        bump = session.bump,
+    )]
+    pub session: Account<'info, VerificationSession>,
+
+    // Program accounts
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FinalizeVerificationInput {
+    pub proof_a: [u8; 64],
+    pub proof_b: [u8; 128],
+    pub proof_c: [u8; 64],
+}
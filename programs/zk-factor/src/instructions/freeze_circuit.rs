@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{Circuit, ZkFactorConfig, ZkFactorError, CIRCUIT_SEED, CONFIG_SEED};
+
+/// Freezing is permanent, the verifying key of a frozen circuit can no longer
+/// be updated. It can still be enabled or disabled.
+pub fn _freeze_circuit(ctx: &mut Context<FreezeCircuitAccounts>) -> Result<()> {
+    let mut circuit = ctx.accounts.circuit.load_mut()?;
+
+    require!(!circuit.is_frozen(), ZkFactorError::CircuitFrozen);
+
+    circuit.frozen = 1;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FreezeCircuitAccounts<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.authority == Some(authority.key()) @ ZkFactorError::Unauthorized,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), circuit.load()?.circuit_id.as_ref()],
+        bump = circuit.load()?.bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    Circuit, ProofBuffer, ZkFactorConfig, ZkFactorError, BUFFER_SEED, CIRCUIT_SEED, CONFIG_SEED,
+    DISCRIMINATOR,
+};
+
+/// Creates a [`ProofBuffer`] sized for the public inputs of `circuit_id`.
+pub fn _init_buffer(ctx: &mut Context<InitBufferAccounts>, _circuit_id: [u8; 32]) -> Result<()> {
+    let circuit = ctx.accounts.circuit.load()?;
+    require!(circuit.is_enabled(), ZkFactorError::CircuitDisabled);
+    let mut buffer = ctx.accounts.buffer.load_init()?;
+
+    buffer.owner = ctx.accounts.owner.key();
+    buffer.circuit = ctx.accounts.circuit.key();
+    buffer.nr_pubinputs = circuit.nr_pubinputs;
+    buffer.bump = ctx.bumps.buffer;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(circuit_id: [u8; 32])]
+pub struct InitBufferAccounts<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), circuit_id.as_ref()],
+        bump = circuit.load()?.bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = DISCRIMINATOR + ProofBuffer::INIT_SPACE,
+        seeds = [BUFFER_SEED, circuit.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub buffer: AccountLoader<'info, ProofBuffer>,
+
+    // Program accounts
+    pub system_program: Program<'info, System>,
+}
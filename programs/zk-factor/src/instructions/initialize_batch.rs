@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    bound_public_inputs, charge_verification_fee, compute_units_since, compute_units_start,
+    create_nullifier, Circuit, Groth16BatchVerifier, InitializeInput, ProofVerified, Treasury,
+    ZkFactorConfig, ZkFactorError, CIRCUIT_SEED, CONFIG_SEED, FACTOR_BOUND_CIRCUIT_ID,
+    PUBLIC_INPUT, TREASURY_SEED,
+};
+
+/// Verifies a batch of proofs, the nullifier PDA of every proof is passed in
+/// the remaining accounts, in the same order as the proofs.
+pub fn _initialize_batch<'info>(
+    ctx: &mut Context<'_, '_, '_, 'info, InitializeBatchAccounts<'info>>,
+    input: InitializeBatchInput,
+) -> Result<()> {
+    let compute_units = compute_units_start();
+    let circuit = ctx.accounts.circuit.load()?;
+    require!(circuit.is_enabled(), ZkFactorError::CircuitDisabled);
+    let vk = circuit.verifying_key();
+    let public_inputs = bound_public_inputs(&PUBLIC_INPUT[0], ctx.accounts.user.key);
+
+    let mut verifier: Groth16BatchVerifier =
+        Groth16BatchVerifier::with_capacity(&vk, input.proofs.len());
+
+    for proof in input.proofs.iter() {
+        verifier.push(
+            &proof.proof_a,
+            &proof.proof_b,
+            &proof.proof_c,
+            &public_inputs,
+        )?;
+    }
+
+    verifier.verify()?;
+
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        input.proofs.len(),
+        ZkFactorError::InvalidNullifierAccounts
+    );
+    let config = ctx.accounts.config.key();
+    for (proof, account) in input.proofs.iter().zip(ctx.remaining_accounts.iter()) {
+        create_nullifier(
+            &ctx.accounts.user,
+            account,
+            &ctx.accounts.system_program,
+            &config,
+            &proof.nullifier(),
+        )?;
+    }
+
+    charge_verification_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.treasury,
+        &ctx.accounts.user,
+        &ctx.accounts.system_program,
+        input.proofs.len() as u64,
+    )?;
+
+    let compute_units = compute_units_since(compute_units);
+    for _ in input.proofs.iter() {
+        emit!(ProofVerified {
+            prover: ctx.accounts.user.key(),
+            circuit: ctx.accounts.circuit.key(),
+            public_inputs: public_inputs.to_vec(),
+            inputs_hash: None,
+            compute_units,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeBatchAccounts<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = !config.locked @ ZkFactorError::PoolLocked,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), FACTOR_BOUND_CIRCUIT_ID.as_ref()],
+        bump = circuit.load()?.bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    // Program accounts
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeBatchInput {
+    pub proofs: Vec<InitializeInput>,
+}
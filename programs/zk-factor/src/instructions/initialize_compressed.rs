@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    bound_public_inputs, charge_verification_fee, compute_units_since, compute_units_start,
+    proof_nullifier, Circuit, Groth16Verifier, Nullifier, ProofVerified, Treasury, ZkFactorConfig,
+    ZkFactorError, CIRCUIT_SEED, CONFIG_SEED, DISCRIMINATOR, FACTOR_BOUND_CIRCUIT_ID,
+    NULLIFIER_SEED, PUBLIC_INPUT, TREASURY_SEED,
+};
+
+pub fn _initialize_compressed(
+    ctx: &mut Context<InitializeCompressedAccounts>,
+    input: InitializeCompressedInput,
+) -> Result<()> {
+    let compute_units = compute_units_start();
+    let circuit = ctx.accounts.circuit.load()?;
+    require!(circuit.is_enabled(), ZkFactorError::CircuitDisabled);
+    let vk = circuit.verifying_key();
+    let public_inputs = bound_public_inputs(&PUBLIC_INPUT[0], ctx.accounts.user.key);
+
+    Groth16Verifier::<'_, 3>::new_compressed(
+        &input.proof_a,
+        &input.proof_b,
+        &input.proof_c,
+        &public_inputs,
+        &vk,
+    )?
+    .prepare_inputs()?
+    .verify()?;
+
+    ctx.accounts.nullifier.consume(ctx.bumps.nullifier)?;
+    charge_verification_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.treasury,
+        &ctx.accounts.user,
+        &ctx.accounts.system_program,
+        1,
+    )?;
+
+    emit!(ProofVerified {
+        prover: ctx.accounts.user.key(),
+        circuit: ctx.accounts.circuit.key(),
+        public_inputs: public_inputs.to_vec(),
+        inputs_hash: None,
+        compute_units: compute_units_since(compute_units),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(input: InitializeCompressedInput)]
+pub struct InitializeCompressedAccounts<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = !config.locked @ ZkFactorError::PoolLocked,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), FACTOR_BOUND_CIRCUIT_ID.as_ref()],
+        bump = circuit.load()?.bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = DISCRIMINATOR + Nullifier::INIT_SPACE,
+        seeds = [NULLIFIER_SEED, config.key().as_ref(), input.nullifier().as_ref()],
+        bump,
+    )]
+    pub nullifier: Account<'info, Nullifier>,
+
+    // Program accounts
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeCompressedInput {
+    pub proof_a: [u8; 32],
+    pub proof_b: [u8; 64],
+    pub proof_c: [u8; 32],
+}
+
+impl InitializeCompressedInput {
+    pub fn nullifier(&self) -> [u8; 32] {
+        proof_nullifier(&self.proof_a, &self.proof_b, &self.proof_c)
+    }
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    charge_verification_fee, compute_units_since, compute_units_start, pubkey_to_scalars,
+    statement_hash, Circuit, Groth16DynVerifier, ProofReceipt, ProofVerified, Treasury,
+    ZkFactorConfig, ZkFactorError, CIRCUIT_SEED, CONFIG_SEED, DISCRIMINATOR, MAX_PUBLIC_INPUTS,
+    RECEIPT_SEED, RECEIPT_VALIDITY, TREASURY_SEED,
+};
+
+/// Verifies a proof and records it in a [`ProofReceipt`].
+///
+/// The circuit must bind the prover like `factor_bound`: its last two public
+/// inputs are the prover pubkey, which are appended to `public_inputs` here,
+/// otherwise anyone could get a receipt for a proof seen on-chain.
+pub fn _issue_receipt(
+    ctx: &mut Context<IssueReceiptAccounts>,
+    input: IssueReceiptInput,
+) -> Result<()> {
+    let compute_units = compute_units_start();
+    let circuit = ctx.accounts.circuit.load()?;
+    require!(circuit.is_enabled(), ZkFactorError::CircuitDisabled);
+    require!(
+        input.public_inputs.len() <= MAX_PUBLIC_INPUTS - 2,
+        ZkFactorError::InvalidNrPublicInputs
+    );
+    let vk = circuit.verifying_key();
+
+    let mut public_inputs = input.public_inputs.clone();
+    public_inputs.extend(pubkey_to_scalars(ctx.accounts.prover.key));
+
+    <Groth16DynVerifier>::new(
+        &input.proof_a,
+        &input.proof_b,
+        &input.proof_c,
+        &public_inputs,
+        &vk,
+    )?
+    .prepare_inputs()?
+    .verify()?;
+    charge_verification_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.treasury,
+        &ctx.accounts.prover,
+        &ctx.accounts.system_program,
+        1,
+    )?;
+
+    emit!(ProofVerified {
+        prover: ctx.accounts.prover.key(),
+        circuit: ctx.accounts.circuit.key(),
+        public_inputs,
+        inputs_hash: None,
+        compute_units: compute_units_since(compute_units),
+    });
+
+    let clock = Clock::get()?;
+    ctx.accounts.receipt.set_inner(ProofReceipt {
+        prover: ctx.accounts.prover.key(),
+        circuit: ctx.accounts.circuit.key(),
+        vk_hash: circuit.vk_hash(),
+        public_inputs: input.public_inputs,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+        expires_at: clock
+            .unix_timestamp
+            .checked_add(RECEIPT_VALIDITY)
+            .ok_or(ZkFactorError::MathOverflow)?,
+        bump: ctx.bumps.receipt,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(input: IssueReceiptInput)]
+pub struct IssueReceiptAccounts<'info> {
+    #[account(mut)]
+    pub prover: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = !config.locked @ ZkFactorError::PoolLocked,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), input.circuit_id.as_ref()],
+        bump = circuit.load()?.bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        init_if_needed,
+        payer = prover,
+        space = DISCRIMINATOR + ProofReceipt::INIT_SPACE,
+        seeds = [
+            RECEIPT_SEED,
+            circuit.key().as_ref(),
+            prover.key().as_ref(),
+            &statement_hash(&input.public_inputs),
+        ],
+        bump,
+    )]
+    pub receipt: Account<'info, ProofReceipt>,
+
+    // Program accounts
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct IssueReceiptInput {
+    pub circuit_id: [u8; 32],
+    pub proof_a: [u8; 64],
+    pub proof_b: [u8; 128],
+    pub proof_c: [u8; 64],
+    /// Public inputs of the statement, without the prover binding.
+    pub public_inputs: Vec<[u8; 32]>,
+}
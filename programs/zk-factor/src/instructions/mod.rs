@@ -1,2 +1,76 @@
+pub mod accept_authority;
+pub mod accumulate_inputs;
+pub mod begin_verification;
+pub mod cancel_verification;
+pub mod close_buffer;
+pub mod close_challenge;
+pub mod close_receipt;
+pub mod create_challenge;
+pub mod create_config;
+pub mod create_token_challenge;
+pub mod deposit;
+pub mod finalize_verification;
+pub mod freeze_circuit;
+pub mod init_buffer;
 pub mod initialize;
+pub mod initialize_batch;
+pub mod initialize_compressed;
+pub mod issue_receipt;
+pub mod propose_authority;
+pub mod reclaim_challenge;
+pub mod reclaim_token_challenge;
+pub mod register_circuit;
+pub mod renounce_authority;
+pub mod set_allowlist_root;
+pub mod set_circuit_enabled;
+pub mod set_fee;
+pub mod set_locked;
+pub mod solve_challenge;
+pub mod solve_token_challenge;
+pub mod swap;
+pub mod update_circuit;
+pub mod verify_from_buffer;
+pub mod verify_groth16;
+pub mod verify_proof;
+pub mod withdraw;
+pub mod withdraw_fees;
+pub mod withdraw_token_fees;
+pub mod write_chunk;
+pub use accept_authority::*;
+pub use accumulate_inputs::*;
+pub use begin_verification::*;
+pub use cancel_verification::*;
+pub use close_buffer::*;
+pub use close_challenge::*;
+pub use close_receipt::*;
+pub use create_challenge::*;
+pub use create_config::*;
+pub use create_token_challenge::*;
+pub use deposit::*;
+pub use finalize_verification::*;
+pub use freeze_circuit::*;
+pub use init_buffer::*;
 pub use initialize::*;
+pub use initialize_batch::*;
+pub use initialize_compressed::*;
+pub use issue_receipt::*;
+pub use propose_authority::*;
+pub use reclaim_challenge::*;
+pub use reclaim_token_challenge::*;
+pub use register_circuit::*;
+pub use renounce_authority::*;
+pub use set_allowlist_root::*;
+pub use set_circuit_enabled::*;
+pub use set_fee::*;
+pub use set_locked::*;
+pub use solve_challenge::*;
+pub use solve_token_challenge::*;
+pub use swap::*;
+pub use update_circuit::*;
+pub use verify_from_buffer::*;
+pub use verify_groth16::*;
+pub use verify_proof::*;
+pub use withdraw::*;
+pub use withdraw_fees::*;
+pub use withdraw_token_fees::*;
+pub use write_chunk::*;
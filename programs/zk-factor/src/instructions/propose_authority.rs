@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{ZkFactorConfig, ZkFactorError, CONFIG_SEED};
+
+/// First step of an authority transfer, `new_authority` has to accept it.
+/// Proposing again replaces the pending authority.
+pub fn _propose_authority(
+    ctx: &mut Context<ProposeAuthorityAccounts>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    ctx.accounts.config.pending_authority = Some(new_authority);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthorityAccounts<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.authority == Some(authority.key()) @ ZkFactorError::Unauthorized,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+}
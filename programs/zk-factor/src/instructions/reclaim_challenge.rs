@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{Challenge, ZkFactorError, CHALLENGE_SEED};
+
+/// Returns the lamport reward of an expired, unsolved challenge to the
+/// poster and closes it.
+pub fn _reclaim_challenge(ctx: &mut Context<ReclaimChallengeAccounts>) -> Result<()> {
+    require!(
+        ctx.accounts
+            .challenge
+            .is_expired(Clock::get()?.unix_timestamp),
+        ZkFactorError::ChallengeNotExpired
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReclaimChallengeAccounts<'info> {
+    #[account(mut)]
+    pub poster: Signer<'info>,
+
+    #[account(
+        mut,
+        close = poster,
+        seeds = [
+            CHALLENGE_SEED,
+            challenge.config.as_ref(),
+            poster.key().as_ref(),
+            &Challenge::modulus_hash(&challenge.modulus),
+        ],
+        bump = challenge.bump,
+        constraint = !challenge.is_solved() @ ZkFactorError::ChallengeAlreadySolved,
+        constraint = challenge.mint.is_none(),
+    )]
+    pub challenge: Account<'info, Challenge>,
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+use crate::{Challenge, ZkFactorError, CHALLENGE_SEED};
+
+/// Returns the token reward of an expired, unsolved challenge to the poster,
+/// closing the vault and the challenge.
+pub fn _reclaim_token_challenge(ctx: &mut Context<ReclaimTokenChallengeAccounts>) -> Result<()> {
+    let challenge = &ctx.accounts.challenge;
+    require!(
+        challenge.is_expired(Clock::get()?.unix_timestamp),
+        ZkFactorError::ChallengeNotExpired
+    );
+
+    let modulus_hash = Challenge::modulus_hash(&challenge.modulus);
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        CHALLENGE_SEED,
+        challenge.config.as_ref(),
+        challenge.poster.as_ref(),
+        modulus_hash.as_ref(),
+        &[challenge.bump],
+    ]];
+
+    // The whole balance rather than the reward, anyone can send tokens to
+    // the vault and it could not be closed with a balance left.
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.poster_ata.to_account_info(),
+                authority: ctx.accounts.challenge.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        ctx.accounts.vault.amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.poster.to_account_info(),
+            authority: ctx.accounts.challenge.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+#[derive(Accounts)]
+pub struct ReclaimTokenChallengeAccounts<'info> {
+    #[account(mut)]
+    pub poster: Signer<'info>,
+
+    #[account(
+        mut,
+        close = poster,
+        seeds = [
+            CHALLENGE_SEED,
+            challenge.config.as_ref(),
+            poster.key().as_ref(),
+            &Challenge::modulus_hash(&challenge.modulus),
+        ],
+        bump = challenge.bump,
+        constraint = !challenge.is_solved() @ ZkFactorError::ChallengeAlreadySolved,
+        constraint = challenge.mint == Some(mint.key()),
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = challenge,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = poster,
+        associated_token::mint = mint,
+        associated_token::authority = poster,
+        associated_token::token_program = token_program,
+    )]
+    pub poster_ata: InterfaceAccount<'info, TokenAccount>,
+
+    // Program accounts
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    Circuit, VerifyingKeyInput, ZkFactorConfig, ZkFactorError, CIRCUIT_SEED, CONFIG_SEED,
+    DISCRIMINATOR,
+};
+
+pub fn _register_circuit(
+    ctx: &mut Context<RegisterCircuitAccounts>,
+    circuit_id: [u8; 32],
+    input: VerifyingKeyInput,
+) -> Result<()> {
+    let mut circuit = ctx.accounts.circuit.load_init()?;
+
+    circuit.set_verifying_key(&input)?;
+    circuit.config = ctx.accounts.config.key();
+    circuit.circuit_id = circuit_id;
+    circuit.version = 1;
+    circuit.enabled = 1;
+    circuit.bump = ctx.bumps.circuit;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(circuit_id: [u8; 32])]
+pub struct RegisterCircuitAccounts<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.authority == Some(authority.key()) @ ZkFactorError::Unauthorized,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = DISCRIMINATOR + Circuit::INIT_SPACE,
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), circuit_id.as_ref()],
+        bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    // Program accounts
+    pub system_program: Program<'info, System>,
+}
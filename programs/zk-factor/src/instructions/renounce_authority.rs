@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{ZkFactorConfig, ZkFactorError, CONFIG_SEED};
+
+/// Removes the authority for good, the fee, the lock and the circuits of the
+/// config can no longer change. The fees drop to zero since nobody could
+/// withdraw them from the treasury anymore, collected fees must be withdrawn
+/// before. A locked config must be unlocked first, nobody could unlock it
+/// afterwards.
+pub fn _renounce_authority(ctx: &mut Context<RenounceAuthorityAccounts>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.authority = None;
+    config.pending_authority = None;
+    config.clear_fees();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RenounceAuthorityAccounts<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.authority == Some(authority.key()) @ ZkFactorError::Unauthorized,
+        constraint = !config.locked @ ZkFactorError::PoolLocked,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+}
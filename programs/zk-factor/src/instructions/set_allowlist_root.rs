@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{is_less_than_bn254_field_size_be, ZkFactorConfig, ZkFactorError, CONFIG_SEED};
+
+/// Replaces the allowlist of the pool, proofs against the previous root stop
+/// being accepted. The root is a public input, a root out of the BN254 field
+/// would make every deposit and swap fail.
+pub fn _set_allowlist_root(
+    ctx: &mut Context<SetAllowlistRootAccounts>,
+    allowlist_root: [u8; 32],
+) -> Result<()> {
+    require!(
+        is_less_than_bn254_field_size_be(&allowlist_root),
+        ZkFactorError::InvalidAllowlistRoot
+    );
+    ctx.accounts.config.allowlist_root = allowlist_root;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAllowlistRootAccounts<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.authority == Some(authority.key()) @ ZkFactorError::Unauthorized,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{Circuit, ZkFactorConfig, ZkFactorError, CIRCUIT_SEED, CONFIG_SEED};
+
+pub fn _set_circuit_enabled(
+    ctx: &mut Context<SetCircuitEnabledAccounts>,
+    enabled: bool,
+) -> Result<()> {
+    let mut circuit = ctx.accounts.circuit.load_mut()?;
+
+    circuit.enabled = enabled as u8;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCircuitEnabledAccounts<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.authority == Some(authority.key()) @ ZkFactorError::Unauthorized,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), circuit.load()?.circuit_id.as_ref()],
+        bump = circuit.load()?.bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+}
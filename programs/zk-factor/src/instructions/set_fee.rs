@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{ZkFactorConfig, ZkFactorError, CONFIG_SEED};
+
+pub fn _set_fee(ctx: &mut Context<SetFeeAccounts>, fee: u16) -> Result<()> {
+    ctx.accounts.config.set_fee(fee)
+}
+
+pub fn _set_swap_fee(ctx: &mut Context<SetFeeAccounts>, swap_fee_bps: u16) -> Result<()> {
+    ctx.accounts.config.set_swap_fee(swap_fee_bps)
+}
+
+/// Sets the lamports charged per verified proof.
+pub fn _set_verification_fee(
+    ctx: &mut Context<SetFeeAccounts>,
+    verification_fee: u64,
+) -> Result<()> {
+    ctx.accounts.config.verification_fee = verification_fee;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFeeAccounts<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.authority == Some(authority.key()) @ ZkFactorError::Unauthorized,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+}
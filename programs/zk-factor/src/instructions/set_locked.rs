@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{ZkFactorConfig, ZkFactorError, CONFIG_SEED};
+
+/// Stops every instruction consuming a proof until `unlock`.
+pub fn _lock(ctx: &mut Context<SetLockedAccounts>) -> Result<()> {
+    ctx.accounts.config.locked = true;
+
+    Ok(())
+}
+
+pub fn _unlock(ctx: &mut Context<SetLockedAccounts>) -> Result<()> {
+    ctx.accounts.config.locked = false;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLockedAccounts<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.authority == Some(authority.key()) @ ZkFactorError::Unauthorized,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+}
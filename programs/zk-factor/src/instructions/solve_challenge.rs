@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    Challenge, ChallengeSolved, Circuit, SolveChallengeInput, Treasury, ZkFactorConfig,
+    ZkFactorError, CHALLENGE_SEED, CONFIG_SEED, TREASURY_SEED,
+};
+
+/// Pays the lamport reward of a challenge to the first valid solver, the fee
+/// going to the treasury.
+pub fn _solve_challenge(
+    ctx: &mut Context<SolveChallengeAccounts>,
+    input: SolveChallengeInput,
+) -> Result<()> {
+    let challenge = &ctx.accounts.challenge;
+    require!(
+        !challenge.is_expired(Clock::get()?.unix_timestamp),
+        ZkFactorError::ChallengeExpired
+    );
+    challenge.verify_solution(
+        &*ctx.accounts.circuit.load()?,
+        ctx.accounts.solver.key,
+        &input,
+    )?;
+
+    let (payout, fee) = ctx.accounts.config.split_fee(challenge.reward)?;
+
+    ctx.accounts.challenge.sub_lamports(challenge.reward)?;
+    ctx.accounts.solver.add_lamports(payout)?;
+    ctx.accounts.treasury.add_lamports(fee)?;
+
+    ctx.accounts.challenge.solver = Some(ctx.accounts.solver.key());
+
+    emit!(ChallengeSolved {
+        challenge: ctx.accounts.challenge.key(),
+        solver: ctx.accounts.solver.key(),
+        modulus: ctx.accounts.challenge.modulus.clone(),
+        mint: ctx.accounts.challenge.mint,
+        payout,
+        fee,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SolveChallengeAccounts<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = !config.locked @ ZkFactorError::PoolLocked,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        address = challenge.circuit,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHALLENGE_SEED,
+            config.key().as_ref(),
+            challenge.poster.as_ref(),
+            &Challenge::modulus_hash(&challenge.modulus),
+        ],
+        bump = challenge.bump,
+        constraint = !challenge.is_solved() @ ZkFactorError::ChallengeAlreadySolved,
+        constraint = challenge.mint.is_none(),
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    // Program accounts
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+use crate::{
+    Challenge, ChallengeSolved, Circuit, SolveChallengeInput, Treasury, ZkFactorConfig,
+    ZkFactorError, CHALLENGE_SEED, CONFIG_SEED, TREASURY_SEED,
+};
+
+/// Pays the token reward of a challenge to the first valid solver, the fee
+/// going to the treasury token account. The emptied vault is closed to the
+/// poster, tokens sent to it besides the reward go to the solver.
+pub fn _solve_token_challenge(
+    ctx: &mut Context<SolveTokenChallengeAccounts>,
+    input: SolveChallengeInput,
+) -> Result<()> {
+    let challenge = &ctx.accounts.challenge;
+    require!(
+        !challenge.is_expired(Clock::get()?.unix_timestamp),
+        ZkFactorError::ChallengeExpired
+    );
+    challenge.verify_solution(
+        &*ctx.accounts.circuit.load()?,
+        ctx.accounts.solver.key,
+        &input,
+    )?;
+
+    let (payout, fee) = ctx.accounts.config.split_fee(challenge.reward)?;
+
+    let config_key = ctx.accounts.config.key();
+    let modulus_hash = Challenge::modulus_hash(&challenge.modulus);
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        CHALLENGE_SEED,
+        config_key.as_ref(),
+        challenge.poster.as_ref(),
+        modulus_hash.as_ref(),
+        &[challenge.bump],
+    ]];
+
+    ctx.accounts.transfer_from_vault(
+        ctx.accounts.solver_ata.to_account_info(),
+        payout,
+        signer_seeds,
+    )?;
+    ctx.accounts.transfer_from_vault(
+        ctx.accounts.treasury_ata.to_account_info(),
+        fee,
+        signer_seeds,
+    )?;
+
+    // Anyone can send tokens to the vault, which could not be closed with a
+    // balance left, the solver gets them on top of the reward.
+    ctx.accounts.vault.reload()?;
+    ctx.accounts.transfer_from_vault(
+        ctx.accounts.solver_ata.to_account_info(),
+        ctx.accounts.vault.amount,
+        signer_seeds,
+    )?;
+
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.poster.to_account_info(),
+            authority: ctx.accounts.challenge.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    ctx.accounts.challenge.solver = Some(ctx.accounts.solver.key());
+
+    emit!(ChallengeSolved {
+        challenge: ctx.accounts.challenge.key(),
+        solver: ctx.accounts.solver.key(),
+        modulus: ctx.accounts.challenge.modulus.clone(),
+        mint: ctx.accounts.challenge.mint,
+        payout,
+        fee,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SolveTokenChallengeAccounts<'info> {
+    #[account(mut)]
+    pub solver: Signer<'info>,
+
+    /// CHECK: receives the vault rent, checked against the challenge.
+    #[account(
+        mut,
+        address = challenge.poster,
+    )]
+    pub poster: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = !config.locked @ ZkFactorError::PoolLocked,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        address = challenge.circuit,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHALLENGE_SEED,
+            config.key().as_ref(),
+            challenge.poster.as_ref(),
+            &Challenge::modulus_hash(&challenge.modulus),
+        ],
+        bump = challenge.bump,
+        constraint = !challenge.is_solved() @ ZkFactorError::ChallengeAlreadySolved,
+        constraint = challenge.mint == Some(mint.key()),
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = challenge,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = solver,
+        associated_token::mint = mint,
+        associated_token::authority = solver,
+        associated_token::token_program = token_program,
+    )]
+    pub solver_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = solver,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
+
+    // Program accounts
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SolveTokenChallengeAccounts<'info> {
+    fn transfer_from_vault(
+        &self,
+        to: AccountInfo<'info>,
+        amount: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.vault.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                    to,
+                    authority: self.challenge.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            self.mint.decimals,
+        )
+    }
+}
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    charge_verification_fee, swap_out, Circuit, PoolAccessProof, Treasury, ZkFactorConfig,
+    ZkFactorError, ALLOWLIST_CIRCUIT_ID, CIRCUIT_SEED, CONFIG_SEED, TREASURY_SEED,
+};
+
+/// Swaps `amount_in` of `mint_x` for `mint_y`, or the other way around when
+/// `is_x` is false, for at least `min_out`. The config swap fee is taken on
+/// the input and stays in the pool.
+pub fn _swap(ctx: &mut Context<SwapAccounts>, input: SwapInput) -> Result<()> {
+    ctx.accounts.config.verify_pool_access(
+        &*ctx.accounts.circuit.load()?,
+        ctx.accounts.user.key,
+        &input.proof,
+    )?;
+    charge_verification_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.treasury,
+        &ctx.accounts.user,
+        &ctx.accounts.system_program,
+        1,
+    )?;
+
+    require!(input.amount_in > 0, ZkFactorError::InvalidAmount);
+    let (vault_in, vault_out) = match input.is_x {
+        true => (ctx.accounts.vault_x.amount, ctx.accounts.vault_y.amount),
+        false => (ctx.accounts.vault_y.amount, ctx.accounts.vault_x.amount),
+    };
+    let amount_out = swap_out(
+        input.amount_in,
+        vault_in,
+        vault_out,
+        ctx.accounts.config.swap_fee_bps,
+    )
+    .ok_or(ZkFactorError::MathOverflow)?;
+    require!(amount_out > 0, ZkFactorError::InvalidAmount);
+    require!(amount_out >= input.min_out, ZkFactorError::SlippageExceeded);
+
+    ctx.accounts.deposit_token(input.is_x, input.amount_in)?;
+    ctx.accounts.withdraw_token(!input.is_x, amount_out)
+}
+
+#[derive(Accounts)]
+pub struct SwapAccounts<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = !config.locked @ ZkFactorError::PoolLocked,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), ALLOWLIST_CIRCUIT_ID.as_ref()],
+        bump = circuit.load()?.bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        address = config.mint_x,
+        mint::token_program = token_program,
+    )]
+    pub mint_x: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        address = config.mint_y,
+        mint::token_program = token_program,
+    )]
+    pub mint_y: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_x,
+        associated_token::authority = config,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_x: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_y,
+        associated_token::authority = config,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_y: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint_x,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_x: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint_y,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_y: InterfaceAccount<'info, TokenAccount>,
+
+    // Program accounts
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl SwapAccounts<'_> {
+    fn deposit_token(&self, is_x: bool, amount: u64) -> Result<()> {
+        let (from, to, mint) = match is_x {
+            true => (&self.user_x, &self.vault_x, &self.mint_x),
+            false => (&self.user_y, &self.vault_y, &self.mint_y),
+        };
+
+        transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: from.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: self.user.to_account_info(),
+                },
+            ),
+            amount,
+            mint.decimals,
+        )
+    }
+
+    fn withdraw_token(&self, is_x: bool, amount: u64) -> Result<()> {
+        let (from, to, mint) = match is_x {
+            true => (&self.vault_x, &self.user_x, &self.mint_x),
+            false => (&self.vault_y, &self.user_y, &self.mint_y),
+        };
+        let seed = self.config.seed.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[CONFIG_SEED, seed.as_ref(), &[self.config.bump]]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: from.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: self.config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            mint.decimals,
+        )
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SwapInput {
+    /// Swap `mint_x` for `mint_y` if true, `mint_y` for `mint_x` otherwise.
+    pub is_x: bool,
+    pub amount_in: u64,
+    pub min_out: u64,
+    pub proof: PoolAccessProof,
+}
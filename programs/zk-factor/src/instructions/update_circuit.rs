@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{Circuit, VerifyingKeyInput, ZkFactorConfig, ZkFactorError, CIRCUIT_SEED, CONFIG_SEED};
+
+pub fn _update_circuit(
+    ctx: &mut Context<UpdateCircuitAccounts>,
+    input: VerifyingKeyInput,
+) -> Result<()> {
+    let mut circuit = ctx.accounts.circuit.load_mut()?;
+
+    require!(!circuit.is_frozen(), ZkFactorError::CircuitFrozen);
+
+    circuit.set_verifying_key(&input)?;
+    circuit.version = circuit
+        .version
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateCircuitAccounts<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.authority == Some(authority.key()) @ ZkFactorError::Unauthorized,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), circuit.load()?.circuit_id.as_ref()],
+        bump = circuit.load()?.bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+}
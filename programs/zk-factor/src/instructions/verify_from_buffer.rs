@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    charge_verification_fee, compute_units_since, compute_units_start, Circuit, Groth16DynVerifier,
+    ProofBuffer, ProofVerified, Treasury, ZkFactorConfig, ZkFactorError, BUFFER_SEED,
+    TREASURY_SEED,
+};
+
+/// Verifies the proof and public inputs uploaded to the buffer, and closes it.
+pub fn _verify_from_buffer(ctx: &mut Context<VerifyFromBufferAccounts>) -> Result<()> {
+    let compute_units = compute_units_start();
+    let circuit = ctx.accounts.circuit.load()?;
+    require!(circuit.is_enabled(), ZkFactorError::CircuitDisabled);
+    let vk = circuit.verifying_key();
+    let buffer = ctx.accounts.buffer.load()?;
+
+    <Groth16DynVerifier>::new(
+        buffer.proof_a(),
+        buffer.proof_b(),
+        buffer.proof_c(),
+        buffer.public_inputs(),
+        &vk,
+    )?
+    .prepare_inputs()?
+    .verify()?;
+    charge_verification_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.treasury,
+        &ctx.accounts.owner,
+        &ctx.accounts.system_program,
+        1,
+    )?;
+
+    emit!(ProofVerified {
+        prover: ctx.accounts.owner.key(),
+        circuit: ctx.accounts.circuit.key(),
+        public_inputs: buffer.public_inputs().to_vec(),
+        inputs_hash: None,
+        compute_units: compute_units_since(compute_units),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VerifyFromBufferAccounts<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        address = buffer.load()?.circuit,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        address = circuit.load()?.config,
+        constraint = !config.locked @ ZkFactorError::PoolLocked,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [BUFFER_SEED, circuit.key().as_ref(), owner.key().as_ref()],
+        bump = buffer.load()?.bump,
+    )]
+    pub buffer: AccountLoader<'info, ProofBuffer>,
+
+    // Program accounts
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    charge_verification_fee, Circuit, Groth16DynVerifier, Groth16Error, Treasury, ZkFactorConfig,
+    ZkFactorError, TREASURY_SEED,
+};
+
+/// Stateless verification for other programs, through CPI with the `cpi`
+/// feature:
+///
+/// ```ignore
+/// let verified = zk_factor::cpi::verify_groth16(cpi_ctx, input)?.get();
+/// require!(verified, MyError::InvalidProof);
+/// ```
+///
+/// The result is set as return data: `true` if the proof is valid, `false`
+/// if the pairing check fails, so that the caller decides what to do with an
+/// invalid proof. Malformed inputs, such as a wrong number of public inputs,
+/// are still errors.
+///
+/// The verification fee is charged whatever the result. No event is emitted,
+/// the caller knows the context of the proof better.
+///
+/// The proof is checked against any registered circuit, it is up to the
+/// caller to check that `circuit` is the one it expects, and to bind the
+/// proof to a signer if it matters.
+pub fn _verify_groth16(
+    ctx: &mut Context<VerifyGroth16Accounts>,
+    input: VerifyGroth16Input,
+) -> Result<bool> {
+    let circuit = ctx.accounts.circuit.load()?;
+    require!(circuit.is_enabled(), ZkFactorError::CircuitDisabled);
+    let vk = circuit.verifying_key();
+
+    let verifier = <Groth16DynVerifier>::new(
+        &input.proof_a,
+        &input.proof_b,
+        &input.proof_c,
+        &input.public_inputs,
+        &vk,
+    )?
+    .prepare_inputs()?;
+
+    charge_verification_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.treasury,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        1,
+    )?;
+
+    match verifier.verify() {
+        Ok(verified) => Ok(verified),
+        Err(Groth16Error::ProofVerificationFailed) => Ok(false),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[derive(Accounts)]
+pub struct VerifyGroth16Accounts<'info> {
+    /// Pays the verification fee.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    #[account(
+        address = circuit.load()?.config,
+        constraint = !config.locked @ ZkFactorError::PoolLocked,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    // Program accounts
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VerifyGroth16Input {
+    pub proof_a: [u8; 64],
+    pub proof_b: [u8; 128],
+    pub proof_c: [u8; 64],
+    pub public_inputs: Vec<[u8; 32]>,
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    charge_verification_fee, compute_units_since, compute_units_start, Circuit, Groth16DynVerifier,
+    ProofVerified, Treasury, ZkFactorConfig, ZkFactorError, CIRCUIT_SEED, CONFIG_SEED,
+    TREASURY_SEED,
+};
+
+/// Verifies a proof against any circuit of the registry.
+pub fn _verify_proof(
+    ctx: &mut Context<VerifyProofAccounts>,
+    input: VerifyProofInput,
+) -> Result<()> {
+    let compute_units = compute_units_start();
+    let circuit = ctx.accounts.circuit.load()?;
+    require!(circuit.is_enabled(), ZkFactorError::CircuitDisabled);
+    let vk = circuit.verifying_key();
+
+    <Groth16DynVerifier>::new(
+        &input.proof_a,
+        &input.proof_b,
+        &input.proof_c,
+        &input.public_inputs,
+        &vk,
+    )?
+    .prepare_inputs()?
+    .verify()?;
+    charge_verification_fee(
+        &ctx.accounts.config,
+        &ctx.accounts.treasury,
+        &ctx.accounts.user,
+        &ctx.accounts.system_program,
+        1,
+    )?;
+
+    emit!(ProofVerified {
+        prover: ctx.accounts.user.key(),
+        circuit: ctx.accounts.circuit.key(),
+        public_inputs: input.public_inputs,
+        inputs_hash: None,
+        compute_units: compute_units_since(compute_units),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(input: VerifyProofInput)]
+pub struct VerifyProofAccounts<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = !config.locked @ ZkFactorError::PoolLocked,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, config.key().as_ref(), input.circuit_id.as_ref()],
+        bump = circuit.load()?.bump,
+    )]
+    pub circuit: AccountLoader<'info, Circuit>,
+
+    // Program accounts
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VerifyProofInput {
+    pub circuit_id: [u8; 32],
+    pub proof_a: [u8; 64],
+    pub proof_b: [u8; 128],
+    pub proof_c: [u8; 64],
+    pub public_inputs: Vec<[u8; 32]>,
+}
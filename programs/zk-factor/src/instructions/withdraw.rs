@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        burn, transfer_checked, Burn, Mint, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+use crate::{withdraw_amounts, ZkFactorConfig, ZkFactorError, CONFIG_SEED, LP_SEED};
+
+/// Burns `lp` LP tokens for their share of the pool, at least `min_x` and
+/// `min_y`.
+///
+/// Unlike deposits and swaps, withdrawals need no allowlist proof and ignore
+/// the lock: rotating the allowlist or locking the config must not trap the
+/// funds of the LPs.
+pub fn _withdraw(ctx: &mut Context<WithdrawAccounts>, input: WithdrawInput) -> Result<()> {
+    require!(input.lp > 0, ZkFactorError::InvalidAmount);
+    let (amount_x, amount_y) = withdraw_amounts(
+        input.lp,
+        ctx.accounts.vault_x.amount,
+        ctx.accounts.vault_y.amount,
+        ctx.accounts.mint_lp.supply,
+    )
+    .ok_or(ZkFactorError::MathOverflow)?;
+    require!(
+        amount_x >= input.min_x && amount_y >= input.min_y,
+        ZkFactorError::SlippageExceeded
+    );
+
+    ctx.accounts.burn_lp(input.lp)?;
+    ctx.accounts.withdraw_token(true, amount_x)?;
+    ctx.accounts.withdraw_token(false, amount_y)
+}
+
+#[derive(Accounts)]
+pub struct WithdrawAccounts<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        address = config.mint_x,
+        mint::token_program = token_program,
+    )]
+    pub mint_x: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        address = config.mint_y,
+        mint::token_program = token_program,
+    )]
+    pub mint_y: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [LP_SEED, config.key().as_ref()],
+        bump = config.lp_bump,
+    )]
+    pub mint_lp: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_x,
+        associated_token::authority = config,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_x: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_y,
+        associated_token::authority = config,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_y: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint_x,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_x: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint_y,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_y: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint_lp,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_lp: InterfaceAccount<'info, TokenAccount>,
+
+    // Program accounts
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl WithdrawAccounts<'_> {
+    fn withdraw_token(&self, is_x: bool, amount: u64) -> Result<()> {
+        let (from, to, mint) = match is_x {
+            true => (&self.vault_x, &self.user_x, &self.mint_x),
+            false => (&self.vault_y, &self.user_y, &self.mint_y),
+        };
+        let seed = self.config.seed.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[CONFIG_SEED, seed.as_ref(), &[self.config.bump]]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: from.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: self.config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            mint.decimals,
+        )
+    }
+
+    fn burn_lp(&self, amount: u64) -> Result<()> {
+        burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.mint_lp.to_account_info(),
+                    from: self.user_lp.to_account_info(),
+                    authority: self.user.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawInput {
+    pub lp: u64,
+    pub min_x: u64,
+    pub min_y: u64,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    withdrawable_lamports, Treasury, ZkFactorConfig, ZkFactorError, CONFIG_SEED, TREASURY_SEED,
+};
+
+/// Withdraws `amount` lamports of collected fees, the treasury keeps its rent
+/// exemption.
+pub fn _withdraw_fees(ctx: &mut Context<WithdrawFeesAccounts>, amount: u64) -> Result<()> {
+    require!(
+        amount <= withdrawable_lamports(&ctx.accounts.treasury.to_account_info())?,
+        ZkFactorError::InsufficientFees
+    );
+
+    ctx.accounts.treasury.sub_lamports(amount)?;
+    ctx.accounts.destination.add_lamports(amount)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFeesAccounts<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: any account can receive lamports.
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.authority == Some(authority.key()) @ ZkFactorError::Unauthorized,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
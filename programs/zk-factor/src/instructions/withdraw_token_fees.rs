@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{Treasury, ZkFactorConfig, ZkFactorError, CONFIG_SEED, TREASURY_SEED};
+
+/// Withdraws `amount` `mint_x` tokens of collected fees.
+pub fn _withdraw_token_fees(
+    ctx: &mut Context<WithdrawTokenFeesAccounts>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        amount <= ctx.accounts.treasury_ata.amount,
+        ZkFactorError::InsufficientFees
+    );
+
+    let config_key = ctx.accounts.config.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        TREASURY_SEED,
+        config_key.as_ref(),
+        &[ctx.accounts.treasury.bump],
+    ]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.treasury_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTokenFeesAccounts<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED, config.seed.to_le_bytes().as_ref()],
+        bump = config.bump,
+        constraint = config.authority == Some(authority.key()) @ ZkFactorError::Unauthorized,
+    )]
+    pub config: Account<'info, ZkFactorConfig>,
+
+    #[account(
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        address = config.mint_x,
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    // Program accounts
+    pub token_program: Interface<'info, TokenInterface>,
+}
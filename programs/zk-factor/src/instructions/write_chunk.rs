@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{ProofBuffer, BUFFER_SEED};
+
+/// Writes `bytes` at `offset` in the buffer, the proof comes first and the
+/// public inputs right after it.
+pub fn _write_chunk(
+    ctx: &mut Context<WriteChunkAccounts>,
+    offset: u32,
+    bytes: Vec<u8>,
+) -> Result<()> {
+    let mut buffer = ctx.accounts.buffer.load_mut()?;
+
+    buffer.write(offset as usize, &bytes)
+}
+
+#[derive(Accounts)]
+pub struct WriteChunkAccounts<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BUFFER_SEED, buffer.load()?.circuit.as_ref(), owner.key().as_ref()],
+        bump = buffer.load()?.bump,
+    )]
+    pub buffer: AccountLoader<'info, ProofBuffer>,
+}
@@ -4,14 +4,15 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod groth16;
 pub mod instructions;
-pub mod macros;
 pub mod state;
 pub mod zk;
 
 pub use constants::*;
 pub use errors::*;
+pub use events::*;
 pub use groth16::*;
 pub use instructions::*;
 pub use state::*;
@@ -23,7 +24,239 @@ declare_id!("zk1PtMyK25jdTA56c7duVC7Zk2gPKy2Y3PiYhJ4L7ro");
 pub mod zk_factor {
     use super::*;
 
+    pub fn create_config(
+        mut ctx: Context<CreateConfigAccounts>,
+        seed: u64,
+        fee: u16,
+        swap_fee_bps: u16,
+        verification_fee: u64,
+    ) -> Result<()> {
+        _create_config(&mut ctx, seed, fee, swap_fee_bps, verification_fee)
+    }
+
+    pub fn set_fee(mut ctx: Context<SetFeeAccounts>, fee: u16) -> Result<()> {
+        _set_fee(&mut ctx, fee)
+    }
+
+    pub fn set_swap_fee(mut ctx: Context<SetFeeAccounts>, swap_fee_bps: u16) -> Result<()> {
+        _set_swap_fee(&mut ctx, swap_fee_bps)
+    }
+
+    pub fn set_verification_fee(
+        mut ctx: Context<SetFeeAccounts>,
+        verification_fee: u64,
+    ) -> Result<()> {
+        _set_verification_fee(&mut ctx, verification_fee)
+    }
+
+    pub fn lock(mut ctx: Context<SetLockedAccounts>) -> Result<()> {
+        _lock(&mut ctx)
+    }
+
+    pub fn unlock(mut ctx: Context<SetLockedAccounts>) -> Result<()> {
+        _unlock(&mut ctx)
+    }
+
+    pub fn propose_authority(
+        mut ctx: Context<ProposeAuthorityAccounts>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        _propose_authority(&mut ctx, new_authority)
+    }
+
+    pub fn accept_authority(mut ctx: Context<AcceptAuthorityAccounts>) -> Result<()> {
+        _accept_authority(&mut ctx)
+    }
+
+    pub fn renounce_authority(mut ctx: Context<RenounceAuthorityAccounts>) -> Result<()> {
+        _renounce_authority(&mut ctx)
+    }
+
+    pub fn withdraw_fees(mut ctx: Context<WithdrawFeesAccounts>, amount: u64) -> Result<()> {
+        _withdraw_fees(&mut ctx, amount)
+    }
+
+    pub fn withdraw_token_fees(
+        mut ctx: Context<WithdrawTokenFeesAccounts>,
+        amount: u64,
+    ) -> Result<()> {
+        _withdraw_token_fees(&mut ctx, amount)
+    }
+
     pub fn initialize(mut ctx: Context<InitializeAccounts>, input: InitializeInput) -> Result<()> {
         _initialize(&mut ctx, input)
     }
+
+    pub fn initialize_batch<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, InitializeBatchAccounts<'info>>,
+        input: InitializeBatchInput,
+    ) -> Result<()> {
+        _initialize_batch(&mut ctx, input)
+    }
+
+    pub fn initialize_compressed(
+        mut ctx: Context<InitializeCompressedAccounts>,
+        input: InitializeCompressedInput,
+    ) -> Result<()> {
+        _initialize_compressed(&mut ctx, input)
+    }
+
+    pub fn verify_proof(
+        mut ctx: Context<VerifyProofAccounts>,
+        input: VerifyProofInput,
+    ) -> Result<()> {
+        _verify_proof(&mut ctx, input)
+    }
+
+    pub fn verify_groth16(
+        mut ctx: Context<VerifyGroth16Accounts>,
+        input: VerifyGroth16Input,
+    ) -> Result<bool> {
+        _verify_groth16(&mut ctx, input)
+    }
+
+    pub fn register_circuit(
+        mut ctx: Context<RegisterCircuitAccounts>,
+        circuit_id: [u8; 32],
+        input: VerifyingKeyInput,
+    ) -> Result<()> {
+        _register_circuit(&mut ctx, circuit_id, input)
+    }
+
+    pub fn update_circuit(
+        mut ctx: Context<UpdateCircuitAccounts>,
+        input: VerifyingKeyInput,
+    ) -> Result<()> {
+        _update_circuit(&mut ctx, input)
+    }
+
+    pub fn freeze_circuit(mut ctx: Context<FreezeCircuitAccounts>) -> Result<()> {
+        _freeze_circuit(&mut ctx)
+    }
+
+    pub fn set_circuit_enabled(
+        mut ctx: Context<SetCircuitEnabledAccounts>,
+        enabled: bool,
+    ) -> Result<()> {
+        _set_circuit_enabled(&mut ctx, enabled)
+    }
+
+    pub fn create_challenge(
+        mut ctx: Context<CreateChallengeAccounts>,
+        modulus: Vec<u64>,
+        reward: u64,
+        deadline: i64,
+    ) -> Result<()> {
+        _create_challenge(&mut ctx, modulus, reward, deadline)
+    }
+
+    pub fn create_token_challenge(
+        mut ctx: Context<CreateTokenChallengeAccounts>,
+        modulus: Vec<u64>,
+        reward: u64,
+        deadline: i64,
+    ) -> Result<()> {
+        _create_token_challenge(&mut ctx, modulus, reward, deadline)
+    }
+
+    pub fn solve_challenge(
+        mut ctx: Context<SolveChallengeAccounts>,
+        input: SolveChallengeInput,
+    ) -> Result<()> {
+        _solve_challenge(&mut ctx, input)
+    }
+
+    pub fn solve_token_challenge(
+        mut ctx: Context<SolveTokenChallengeAccounts>,
+        input: SolveChallengeInput,
+    ) -> Result<()> {
+        _solve_token_challenge(&mut ctx, input)
+    }
+
+    pub fn reclaim_challenge(mut ctx: Context<ReclaimChallengeAccounts>) -> Result<()> {
+        _reclaim_challenge(&mut ctx)
+    }
+
+    pub fn reclaim_token_challenge(mut ctx: Context<ReclaimTokenChallengeAccounts>) -> Result<()> {
+        _reclaim_token_challenge(&mut ctx)
+    }
+
+    pub fn close_challenge(mut ctx: Context<CloseChallengeAccounts>) -> Result<()> {
+        _close_challenge(&mut ctx)
+    }
+
+    pub fn issue_receipt(
+        mut ctx: Context<IssueReceiptAccounts>,
+        input: IssueReceiptInput,
+    ) -> Result<()> {
+        _issue_receipt(&mut ctx, input)
+    }
+
+    pub fn close_receipt(mut ctx: Context<CloseReceiptAccounts>) -> Result<()> {
+        _close_receipt(&mut ctx)
+    }
+
+    pub fn begin_verification(
+        mut ctx: Context<BeginVerificationAccounts>,
+        circuit_id: [u8; 32],
+    ) -> Result<()> {
+        _begin_verification(&mut ctx, circuit_id)
+    }
+
+    pub fn accumulate_inputs(
+        mut ctx: Context<AccumulateInputsAccounts>,
+        public_inputs: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        _accumulate_inputs(&mut ctx, public_inputs)
+    }
+
+    pub fn finalize_verification(
+        mut ctx: Context<FinalizeVerificationAccounts>,
+        input: FinalizeVerificationInput,
+    ) -> Result<()> {
+        _finalize_verification(&mut ctx, input)
+    }
+
+    pub fn cancel_verification(mut ctx: Context<CancelVerificationAccounts>) -> Result<()> {
+        _cancel_verification(&mut ctx)
+    }
+
+    pub fn init_buffer(mut ctx: Context<InitBufferAccounts>, circuit_id: [u8; 32]) -> Result<()> {
+        _init_buffer(&mut ctx, circuit_id)
+    }
+
+    pub fn write_chunk(
+        mut ctx: Context<WriteChunkAccounts>,
+        offset: u32,
+        bytes: Vec<u8>,
+    ) -> Result<()> {
+        _write_chunk(&mut ctx, offset, bytes)
+    }
+
+    pub fn verify_from_buffer(mut ctx: Context<VerifyFromBufferAccounts>) -> Result<()> {
+        _verify_from_buffer(&mut ctx)
+    }
+
+    pub fn close_buffer(mut ctx: Context<CloseBufferAccounts>) -> Result<()> {
+        _close_buffer(&mut ctx)
+    }
+
+    pub fn set_allowlist_root(
+        mut ctx: Context<SetAllowlistRootAccounts>,
+        allowlist_root: [u8; 32],
+    ) -> Result<()> {
+        _set_allowlist_root(&mut ctx, allowlist_root)
+    }
+
+    pub fn deposit(mut ctx: Context<DepositAccounts>, input: DepositInput) -> Result<()> {
+        _deposit(&mut ctx, input)
+    }
+
+    pub fn withdraw(mut ctx: Context<WithdrawAccounts>, input: WithdrawInput) -> Result<()> {
+        _withdraw(&mut ctx, input)
+    }
+
+    pub fn swap(mut ctx: Context<SwapAccounts>, input: SwapInput) -> Result<()> {
+        _swap(&mut ctx, input)
+    }
 }
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{ZkFactorError, MAX_PUBLIC_INPUTS};
+
+/// Size of an uncompressed proof: `proof_a || proof_b || proof_c`.
+pub const PROOF_SIZE: usize = 64 + 128 + 64;
+
+/// Upload buffer for proofs whose public inputs do not fit in a single
+/// transaction.
+///
+/// `data` holds the proof followed by the public inputs, written in chunks
+/// with `write_chunk`, then `verify_from_buffer` verifies it and closes the
+/// buffer. `close_buffer` abandons it.
+#[account(zero_copy)]
+pub struct ProofBuffer {
+    pub owner: Pubkey,
+    pub circuit: Pubkey,
+    pub data: [u8; PROOF_SIZE + 32 * MAX_PUBLIC_INPUTS],
+    pub nr_pubinputs: u8,
+    pub bump: u8,
+}
+
+impl ProofBuffer {
+    pub const INIT_SPACE: usize = std::mem::size_of::<ProofBuffer>();
+
+    /// Length of the proof and public inputs of the buffer circuit.
+    pub fn data_len(&self) -> usize {
+        PROOF_SIZE + 32 * self.nr_pubinputs as usize
+    }
+
+    pub fn write(&mut self, offset: usize, bytes: &[u8]) -> Result<()> {
+        let end = offset
+            .checked_add(bytes.len())
+            .filter(|end| *end <= self.data_len())
+            .ok_or(ZkFactorError::BufferOverflow)?;
+
+        self.data[offset..end].copy_from_slice(bytes);
+
+        Ok(())
+    }
+
+    pub fn proof_a(&self) -> &[u8; 64] {
+        self.data[0..64].try_into().unwrap()
+    }
+
+    pub fn proof_b(&self) -> &[u8; 128] {
+        self.data[64..192].try_into().unwrap()
+    }
+
+    pub fn proof_c(&self) -> &[u8; 64] {
+        self.data[192..PROOF_SIZE].try_into().unwrap()
+    }
+
+    pub fn public_inputs(&self) -> &[[u8; 32]] {
+        bytemuck::cast_slice(&self.data[PROOF_SIZE..self.data_len()])
+    }
+}
@@ -0,0 +1,128 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::{
+    is_canonical_modulus, pad_limbs, Circuit, Groth16DynVerifier, PublicInputs, ZkFactorError,
+    MAX_MODULUS_LIMBS,
+};
+
+/// Factoring bounty: the first solver proving knowledge of `p * q = modulus`
+/// with the challenge circuit before the deadline gets the escrowed reward,
+/// minus the config fee. Once the deadline has passed unsolved, the poster
+/// can reclaim the reward, once solved the poster closes it to get the rent
+/// back.
+///
+/// The reward is held in lamports by the challenge account itself, or in
+/// `mint_x` tokens by its associated token account.
+///
+/// The circuit is the `factor_limbs` circuit of the config, a poster cannot
+/// pick one that accepts any solution, and its authority cannot swap the
+/// verifying key under open challenges. Its public inputs are the modulus
+/// limbs followed by `[solver_hi, solver_lo]`, so a solution cannot be
+/// front-run, and it range checks the limbs of `p` and `q` and rejects
+/// `p = 1` or `q = 1`.
+#[account]
+#[derive(InitSpace)]
+pub struct Challenge {
+    pub config: Pubkey,
+    pub poster: Pubkey,
+    pub circuit: Pubkey,
+    /// Circuit version the challenge was posted against, a verifying key
+    /// update cannot change what solves it.
+    pub circuit_version: u32,
+    /// Modulus to factor, as little-endian 64-bit limbs, see
+    /// [`crate::to_limbs`].
+    #[max_len(MAX_MODULUS_LIMBS)]
+    pub modulus: Vec<u64>,
+    pub reward: u64,
+    /// `None` when the reward is in lamports.
+    pub mint: Option<Pubkey>,
+    /// Unix timestamp after which solutions are rejected.
+    pub deadline: i64,
+    pub solver: Option<Pubkey>,
+    pub bump: u8,
+}
+
+impl Challenge {
+    pub fn is_solved(&self) -> bool {
+        self.solver.is_some()
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.deadline
+    }
+
+    /// Seed of the challenge PDA, the modulus limbs do not fit in a seed.
+    /// Passed as `&Challenge::modulus_hash(..)`, see [`crate::statement_hash`].
+    pub fn modulus_hash(modulus: &[u64]) -> [u8; 32] {
+        let limbs: Vec<[u8; 8]> = modulus.iter().map(|limb| limb.to_le_bytes()).collect();
+        let data: Vec<&[u8]> = limbs.iter().map(|limb| limb.as_slice()).collect();
+        hashv(&data).to_bytes()
+    }
+
+    /// Checks that `modulus` can be posted with `circuit`: canonical limbs,
+    /// and no more limbs than the circuit has public inputs for besides the
+    /// solver pubkey. Shorter moduli are zero-padded, see
+    /// [`Challenge::public_limbs`].
+    pub fn check_modulus(modulus: &[u64], circuit: &Circuit) -> Result<()> {
+        require!(
+            modulus.len() <= MAX_MODULUS_LIMBS && is_canonical_modulus(modulus),
+            ZkFactorError::InvalidModulus
+        );
+        require_gte!(
+            circuit.nr_pubinputs as usize,
+            modulus.len() + 2,
+            ZkFactorError::InvalidNrPublicInputs
+        );
+
+        Ok(())
+    }
+
+    /// Modulus limbs as public inputs of `circuit`, zero-padded to its `k`
+    /// limbs.
+    pub fn public_limbs(&self, circuit: &Circuit) -> Vec<u64> {
+        pad_limbs(
+            &self.modulus,
+            (circuit.nr_pubinputs as usize).saturating_sub(2),
+        )
+    }
+
+    /// Verifies the factoring proof of `solver` against the modulus of this
+    /// challenge.
+    pub fn verify_solution(
+        &self,
+        circuit: &Circuit,
+        solver: &Pubkey,
+        input: &SolveChallengeInput,
+    ) -> Result<()> {
+        require!(circuit.is_enabled(), ZkFactorError::CircuitDisabled);
+        require_eq!(
+            self.circuit_version,
+            circuit.version,
+            ZkFactorError::CircuitUpdated
+        );
+        let vk = circuit.verifying_key();
+        let public_inputs = PublicInputs::new()
+            .limbs(&self.public_limbs(circuit))
+            .pubkey(solver)
+            .build();
+
+        <Groth16DynVerifier>::new(
+            &input.proof_a,
+            &input.proof_b,
+            &input.proof_c,
+            &public_inputs,
+            &vk,
+        )?
+        .prepare_inputs()?
+        .verify()?;
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SolveChallengeInput {
+    pub proof_a: [u8; 64],
+    pub proof_b: [u8; 128],
+    pub proof_c: [u8; 64],
+}
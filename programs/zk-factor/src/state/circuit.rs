@@ -0,0 +1,113 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::{Groth16Verifyingkey, ZkFactorError, MAX_PUBLIC_INPUTS};
+
+/// Registry entry of a circuit: its Groth16 verifying key stored on-chain, so
+/// that circuits can be added or rotated without redeploying the program.
+#[account(zero_copy)]
+pub struct Circuit {
+    pub config: Pubkey,
+    pub circuit_id: [u8; 32],
+    /// Bumped on every verifying key update.
+    pub version: u32,
+    pub vk_alpha_g1: [u8; 64],
+    pub vk_beta_g2: [u8; 128],
+    pub vk_gamme_g2: [u8; 128],
+    pub vk_delta_g2: [u8; 128],
+    pub vk_ic: [[u8; 64]; MAX_PUBLIC_INPUTS + 1],
+    pub nr_pubinputs: u8,
+    pub enabled: u8,
+    pub frozen: u8,
+    pub bump: u8,
+}
+
+impl Circuit {
+    pub const INIT_SPACE: usize = std::mem::size_of::<Circuit>();
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled != 0
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen != 0
+    }
+
+    pub fn verifying_key(&self) -> Groth16Verifyingkey<'_> {
+        Groth16Verifyingkey {
+            nr_pubinputs: self.nr_pubinputs as usize,
+            vk_alpha_g1: self.vk_alpha_g1,
+            vk_beta_g2: self.vk_beta_g2,
+            vk_gamme_g2: self.vk_gamme_g2,
+            vk_delta_g2: self.vk_delta_g2,
+            vk_ic: &self.vk_ic[..self.nr_pubinputs as usize + 1],
+        }
+    }
+
+    /// Hash of the verifying key, identifies which key a proof was checked
+    /// against across updates of the circuit.
+    pub fn vk_hash(&self) -> [u8; 32] {
+        let vk = self.verifying_key();
+        let mut data: Vec<&[u8]> = vec![
+            &vk.vk_alpha_g1,
+            &vk.vk_beta_g2,
+            &vk.vk_gamme_g2,
+            &vk.vk_delta_g2,
+        ];
+        data.extend(vk.vk_ic.iter().map(|ic| ic.as_slice()));
+
+        hashv(&data).to_bytes()
+    }
+
+    /// Stores a verifying key after [`Groth16Verifyingkey::validate`], the
+    /// register and update instructions need a raised compute unit limit.
+    pub fn set_verifying_key(&mut self, input: &VerifyingKeyInput) -> Result<()> {
+        require!(
+            !input.vk_ic.is_empty() && input.vk_ic.len() <= MAX_PUBLIC_INPUTS + 1,
+            ZkFactorError::InvalidNrPublicInputs
+        );
+
+        Groth16Verifyingkey {
+            nr_pubinputs: input.vk_ic.len() - 1,
+            vk_alpha_g1: input.vk_alpha_g1,
+            vk_beta_g2: input.vk_beta_g2,
+            vk_gamme_g2: input.vk_gamme_g2,
+            vk_delta_g2: input.vk_delta_g2,
+            vk_ic: &input.vk_ic,
+        }
+        .validate()?;
+
+        self.vk_alpha_g1 = input.vk_alpha_g1;
+        self.vk_beta_g2 = input.vk_beta_g2;
+        self.vk_gamme_g2 = input.vk_gamme_g2;
+        self.vk_delta_g2 = input.vk_delta_g2;
+        self.vk_ic = [[0u8; 64]; MAX_PUBLIC_INPUTS + 1];
+        self.vk_ic[..input.vk_ic.len()].copy_from_slice(&input.vk_ic);
+        self.nr_pubinputs = (input.vk_ic.len() - 1) as u8;
+
+        Ok(())
+    }
+}
+
+/// Circuit ids are the circuit name, zero padded to 32 bytes, so they can be
+/// used as PDA seeds and computed at compile time.
+pub const fn circuit_id(name: &str) -> [u8; 32] {
+    let name = name.as_bytes();
+    assert!(name.len() <= 32, "circuit name is longer than 32 bytes");
+
+    let mut id = [0u8; 32];
+    let mut i = 0;
+    while i < name.len() {
+        id[i] = name[i];
+        i += 1;
+    }
+    id
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VerifyingKeyInput {
+    pub vk_alpha_g1: [u8; 64],
+    pub vk_beta_g2: [u8; 128],
+    pub vk_gamme_g2: [u8; 128],
+    pub vk_delta_g2: [u8; 128],
+    pub vk_ic: Vec<[u8; 64]>,
+}
@@ -1,14 +1,55 @@
 use anchor_lang::prelude::*;
 
+use crate::{split_fee, ZkFactorError, MAX_FEE_BPS};
+
 #[account]
 #[derive(InitSpace)]
 pub struct ZkFactorConfig {
+    /// `None` once the authority is renounced, the config is then immutable.
     pub authority: Option<Pubkey>,
+    /// Proposed by the authority, becomes the authority once it accepts.
+    pub pending_authority: Option<Pubkey>,
     pub seed: u64,
+    /// Fee in basis points, taken on challenge rewards.
     pub fee: u16,
+    /// Fee in basis points, taken on swap inputs. It stays in the pool and
+    /// goes to the LPs, so it is kept when the authority is renounced.
+    pub swap_fee_bps: u16,
+    /// Lamports charged per verified proof.
+    pub verification_fee: u64,
+    /// Stops every instruction consuming a proof.
     pub locked: bool,
     pub mint_x: Pubkey,
     pub mint_y: Pubkey,
+    /// Root of the allowlist of the pool, users prove their membership with
+    /// the `allowlist` circuit.
+    pub allowlist_root: [u8; 32],
     pub lp_bump: u8,
     pub bump: u8,
 }
+
+impl ZkFactorConfig {
+    pub fn set_fee(&mut self, fee: u16) -> Result<()> {
+        require!(fee <= MAX_FEE_BPS, ZkFactorError::InvalidFee);
+        self.fee = fee;
+        Ok(())
+    }
+
+    pub fn set_swap_fee(&mut self, swap_fee_bps: u16) -> Result<()> {
+        require!(swap_fee_bps <= MAX_FEE_BPS, ZkFactorError::InvalidFee);
+        self.swap_fee_bps = swap_fee_bps;
+        Ok(())
+    }
+
+    /// Stops the collection of new fees, used when the authority is
+    /// renounced since nobody could withdraw them anymore.
+    pub fn clear_fees(&mut self) {
+        self.fee = 0;
+        self.verification_fee = 0;
+    }
+
+    /// Splits `amount` into the payout and the protocol fee.
+    pub fn split_fee(&self, amount: u64) -> Result<(u64, u64)> {
+        split_fee(amount, self.fee).ok_or(ZkFactorError::MathOverflow.into())
+    }
+}
@@ -1,2 +1,18 @@
+pub mod buffer;
+pub mod challenge;
+pub mod circuit;
 pub mod config;
+pub mod nullifier;
+pub mod pool;
+pub mod receipt;
+pub mod session;
+pub mod treasury;
+pub use buffer::*;
+pub use challenge::*;
+pub use circuit::*;
 pub use config::*;
+pub use nullifier::*;
+pub use pool::*;
+pub use receipt::*;
+pub use session::*;
+pub use treasury::*;
@@ -0,0 +1,136 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::hash::hashv,
+    system_program::{
+        allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+    },
+    Discriminator,
+};
+
+use crate::{ZkFactorError, DISCRIMINATOR, NULLIFIER_SEED};
+
+/// Domain separator of proof nullifiers.
+pub const NULLIFIER_DOMAIN: &[u8] = b"zk-factor:nullifier:v1";
+
+/// Marks a proof as consumed, so that it cannot be replayed.
+///
+/// The PDA is derived from [`proof_nullifier`] and created the first time
+/// the proof is submitted.
+#[account]
+#[derive(InitSpace)]
+pub struct Nullifier {
+    pub used: bool,
+    pub bump: u8,
+}
+
+impl Nullifier {
+    pub fn consume(&mut self, bump: u8) -> Result<()> {
+        require!(!self.used, ZkFactorError::ProofAlreadyUsed);
+        self.used = true;
+        self.bump = bump;
+        Ok(())
+    }
+}
+
+/// Derives the nullifier of a proof from the x coordinates of its points.
+///
+/// Points can be compressed or not: compressed points are their x coordinate
+/// with flags in the two top bits, which are masked out. The y coordinates
+/// are left out so that `(-A, -B, C)`, which verifies like `(A, B, C)`, has
+/// the same nullifier.
+///
+/// Groth16 proofs can still be re-randomized by their prover, the nullifier
+/// only stops someone else from replaying a proof seen on-chain, together
+/// with the signer binding of the circuit.
+pub fn proof_nullifier(proof_a: &[u8], proof_b: &[u8], proof_c: &[u8]) -> [u8; 32] {
+    let mut a_x: [u8; 32] = proof_a[..32].try_into().unwrap();
+    let mut b_x: [u8; 64] = proof_b[..64].try_into().unwrap();
+    let mut c_x: [u8; 32] = proof_c[..32].try_into().unwrap();
+    a_x[0] &= 0x3f;
+    b_x[0] &= 0x3f;
+    c_x[0] &= 0x3f;
+
+    hashv(&[NULLIFIER_DOMAIN, &a_x, &b_x, &c_x]).to_bytes()
+}
+
+/// Creates the nullifier PDA of `nullifier` at `account`, for instructions
+/// that consume a variable number of proofs through remaining accounts.
+///
+/// Fails with [`ZkFactorError::ProofAlreadyUsed`] if it already exists.
+pub fn create_nullifier<'info>(
+    payer: &Signer<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    config: &Pubkey,
+    nullifier: &[u8; 32],
+) -> Result<()> {
+    let (address, bump) = Pubkey::find_program_address(
+        &[NULLIFIER_SEED, config.as_ref(), nullifier.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(account.key(), address, ErrorCode::ConstraintSeeds);
+    require!(account.owner != &crate::ID, ZkFactorError::ProofAlreadyUsed);
+
+    let space = DISCRIMINATOR + Nullifier::INIT_SPACE;
+    let signer_seeds: &[&[&[u8]]] =
+        &[&[NULLIFIER_SEED, config.as_ref(), nullifier.as_ref(), &[bump]]];
+    let rent = Rent::get()?.minimum_balance(space);
+
+    // Same as anchor's `init`: the address may already hold lamports, in
+    // which case `create_account` would fail.
+    if account.lamports() == 0 {
+        create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        )?;
+    } else {
+        let top_up = rent.saturating_sub(account.lamports());
+        if top_up > 0 {
+            transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    Transfer {
+                        from: payer.to_account_info(),
+                        to: account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        allocate(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                Allocate {
+                    account_to_allocate: account.clone(),
+                },
+                signer_seeds,
+            ),
+            space as u64,
+        )?;
+        assign(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                Assign {
+                    account_to_assign: account.clone(),
+                },
+                signer_seeds,
+            ),
+            &crate::ID,
+        )?;
+    }
+
+    let mut data = account.try_borrow_mut_data()?;
+    data[..DISCRIMINATOR].copy_from_slice(Nullifier::DISCRIMINATOR);
+    Nullifier { used: true, bump }.serialize(&mut &mut data[DISCRIMINATOR..])?;
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    bound_public_inputs, calculate_fee, Circuit, Groth16Verifier, ZkFactorConfig, ZkFactorError,
+    MINIMUM_LIQUIDITY,
+};
+
+/// Proof that the user is in the allowlist of the pool: a proof of the
+/// `allowlist` circuit, whose public inputs are
+/// `[allowlist_root, user_hi, user_lo]`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PoolAccessProof {
+    pub proof_a: [u8; 64],
+    pub proof_b: [u8; 128],
+    pub proof_c: [u8; 64],
+}
+
+impl ZkFactorConfig {
+    /// Checks that `user` proved its membership of the pool allowlist.
+    pub fn verify_pool_access(
+        &self,
+        circuit: &Circuit,
+        user: &Pubkey,
+        proof: &PoolAccessProof,
+    ) -> Result<()> {
+        require!(circuit.is_enabled(), ZkFactorError::CircuitDisabled);
+        let vk = circuit.verifying_key();
+        let public_inputs = bound_public_inputs(&self.allowlist_root, user);
+
+        Groth16Verifier::<'_, 3>::new(
+            &proof.proof_a,
+            &proof.proof_b,
+            &proof.proof_c,
+            &public_inputs,
+            &vk,
+        )?
+        .prepare_inputs()?
+        .verify()?;
+
+        Ok(())
+    }
+}
+
+/// LP minted by the first deposit: `sqrt(x * y)`, rounded down.
+pub fn initial_lp(amount_x: u64, amount_y: u64) -> Option<u64> {
+    let product = (amount_x as u128).checked_mul(amount_y as u128)?;
+    isqrt(product).try_into().ok()
+}
+
+/// LP of the first depositor, [`initial_lp`] minus the [`MINIMUM_LIQUIDITY`]
+/// locked in the pool.
+pub fn first_deposit_lp(amount_x: u64, amount_y: u64) -> Option<u64> {
+    initial_lp(amount_x, amount_y)?.checked_sub(MINIMUM_LIQUIDITY)
+}
+
+/// Tokens to deposit to mint `lp` LP, rounded up in favour of the pool.
+pub fn deposit_amounts(lp: u64, vault_x: u64, vault_y: u64, supply: u64) -> Option<(u64, u64)> {
+    Some((
+        mul_div_ceil(lp, vault_x, supply)?,
+        mul_div_ceil(lp, vault_y, supply)?,
+    ))
+}
+
+/// Tokens returned for burning `lp` LP, rounded down in favour of the pool.
+pub fn withdraw_amounts(lp: u64, vault_x: u64, vault_y: u64, supply: u64) -> Option<(u64, u64)> {
+    if lp > supply {
+        return None;
+    }
+
+    Some((
+        mul_div_floor(lp, vault_x, supply)?,
+        mul_div_floor(lp, vault_y, supply)?,
+    ))
+}
+
+/// Output of a swap of `amount_in` on the `x * y = k` curve, the fee of
+/// `fee_bps` is taken on the input and stays in the pool.
+pub fn swap_out(amount_in: u64, vault_in: u64, vault_out: u64, fee_bps: u16) -> Option<u64> {
+    let amount_in = amount_in.checked_sub(calculate_fee(amount_in, fee_bps)?)? as u128;
+    let numerator = amount_in.checked_mul(vault_out as u128)?;
+    let denominator = (vault_in as u128).checked_add(amount_in)?;
+
+    numerator.checked_div(denominator)?.try_into().ok()
+}
+
+fn mul_div_floor(a: u64, b: u64, c: u64) -> Option<u64> {
+    (a as u128)
+        .checked_mul(b as u128)?
+        .checked_div(c as u128)?
+        .try_into()
+        .ok()
+}
+
+fn mul_div_ceil(a: u64, b: u64, c: u64) -> Option<u64> {
+    if c == 0 {
+        return None;
+    }
+
+    (a as u128)
+        .checked_mul(b as u128)?
+        .checked_add(c as u128 - 1)?
+        .checked_div(c as u128)?
+        .try_into()
+        .ok()
+}
+
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method, starting above the root.
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
@@ -0,0 +1,40 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::MAX_PUBLIC_INPUTS;
+
+/// Attestation that `prover` proved the statement `public_inputs` with the
+/// verifying key `vk_hash` of `circuit`, so that other programs and
+/// off-chain services can check it without verifying the proof again.
+///
+/// The PDA is derived from the circuit, the prover and [`statement_hash`],
+/// proving the same statement again refreshes the receipt.
+#[account]
+#[derive(InitSpace)]
+pub struct ProofReceipt {
+    pub prover: Pubkey,
+    pub circuit: Pubkey,
+    pub vk_hash: [u8; 32],
+    /// Public inputs of the statement, without the prover binding.
+    #[max_len(MAX_PUBLIC_INPUTS)]
+    pub public_inputs: Vec<[u8; 32]>,
+    pub slot: u64,
+    pub timestamp: i64,
+    /// Unix timestamp after which the receipt can be closed.
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl ProofReceipt {
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// Hash of the public inputs of a statement, used as a receipt seed.
+///
+/// Seeds take `&statement_hash(..)` rather than `.as_ref()`: the IDL build
+/// with PDA resolution copies a method call seed as is and fails to compile.
+pub fn statement_hash(public_inputs: &[[u8; 32]]) -> [u8; 32] {
+    let data: Vec<&[u8]> = public_inputs.iter().map(|input| input.as_slice()).collect();
+    hashv(&data).to_bytes()
+}
@@ -0,0 +1,68 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::{fold_public_inputs, Circuit, ZkFactorError};
+
+/// Scratch account of a verification split across instructions, for circuits
+/// with too many public inputs to prepare and pair in a single one.
+///
+/// Public inputs are folded into `prepared_public_inputs` in chunks, then the
+/// pairing runs from it and the session is closed. The owner can cancel the
+/// session at any point, e.g. after folding wrong inputs.
+#[account]
+#[derive(InitSpace)]
+pub struct VerificationSession {
+    pub owner: Pubkey,
+    pub circuit: Pubkey,
+    /// Circuit version the session started with, a verifying key update
+    /// invalidates the folded inputs.
+    pub circuit_version: u32,
+    pub nr_pubinputs: u8,
+    /// Number of public inputs folded so far.
+    pub next_input: u8,
+    pub prepared_public_inputs: [u8; 64],
+    /// Hash chain of the folded inputs, see [`chain_inputs_hash`].
+    pub inputs_hash: [u8; 32],
+    pub bump: u8,
+}
+
+impl VerificationSession {
+    pub fn is_complete(&self) -> bool {
+        self.next_input == self.nr_pubinputs
+    }
+
+    /// Folds the next `public_inputs` into the prepared inputs.
+    pub fn accumulate(&mut self, circuit: &Circuit, public_inputs: &[[u8; 32]]) -> Result<()> {
+        require_eq!(
+            self.circuit_version,
+            circuit.version,
+            ZkFactorError::CircuitUpdated
+        );
+
+        let start = self.next_input as usize;
+        let end = start
+            .checked_add(public_inputs.len())
+            .filter(|end| *end <= self.nr_pubinputs as usize)
+            .ok_or(ZkFactorError::InvalidNrPublicInputs)?;
+
+        let vk = circuit.verifying_key();
+        self.prepared_public_inputs = fold_public_inputs(
+            &self.prepared_public_inputs,
+            &vk.vk_ic[start + 1..end + 1],
+            public_inputs,
+            true,
+        )?;
+        self.inputs_hash = chain_inputs_hash(&self.inputs_hash, public_inputs);
+        self.next_input = end as u8;
+
+        Ok(())
+    }
+}
+
+/// Extends `hash` with `public_inputs`, one `sha256(hash || input)` per
+/// input starting from zeros, so the result does not depend on how the
+/// inputs were split in chunks.
+pub fn chain_inputs_hash(hash: &[u8; 32], public_inputs: &[[u8; 32]]) -> [u8; 32] {
+    public_inputs.iter().fold(*hash, |hash, input| {
+        hashv(&[hash.as_slice(), input.as_slice()]).to_bytes()
+    })
+}
@@ -0,0 +1,76 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{ZkFactorConfig, ZkFactorError, MAX_FEE_BPS};
+
+/// Collects the protocol fees of a config: lamports on the account itself,
+/// `mint_x` tokens on its associated token account. Only the config
+/// authority can withdraw them.
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    pub config: Pubkey,
+    pub bump: u8,
+}
+
+/// Lamports of the treasury above its rent exemption.
+pub fn withdrawable_lamports(treasury: &AccountInfo) -> Result<u64> {
+    let rent = Rent::get()?.minimum_balance(treasury.data_len());
+    Ok(treasury.lamports().saturating_sub(rent))
+}
+
+/// Charges `payer` the verification fee of `nr_proofs` proofs, returns the
+/// amount charged.
+pub fn charge_verification_fee<'info>(
+    config: &ZkFactorConfig,
+    treasury: &Account<'info, Treasury>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    nr_proofs: u64,
+) -> Result<u64> {
+    let fee =
+        verification_fee(config.verification_fee, nr_proofs).ok_or(ZkFactorError::MathOverflow)?;
+    if fee == 0 {
+        return Ok(0);
+    }
+
+    transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: payer.to_account_info(),
+                to: treasury.to_account_info(),
+            },
+        ),
+        fee,
+    )?;
+
+    Ok(fee)
+}
+
+/// Lamports charged for verifying `nr_proofs` proofs at `fee_per_proof`.
+pub fn verification_fee(fee_per_proof: u64, nr_proofs: u64) -> Option<u64> {
+    fee_per_proof.checked_mul(nr_proofs)
+}
+
+/// Splits `amount` into the payout and the fee of `fee_bps`.
+pub fn split_fee(amount: u64, fee_bps: u16) -> Option<(u64, u64)> {
+    let fee = calculate_fee(amount, fee_bps)?;
+    Some((amount.checked_sub(fee)?, fee))
+}
+
+/// `amount * fee_bps / 10_000`, rounded down. `None` if `fee_bps` is above
+/// 100%.
+pub fn calculate_fee(amount: u64, fee_bps: u16) -> Option<u64> {
+    if fee_bps > MAX_FEE_BPS {
+        return None;
+    }
+
+    (amount as u128)
+        .checked_mul(fee_bps as u128)?
+        .checked_div(MAX_FEE_BPS as u128)?
+        .try_into()
+        .ok()
+}
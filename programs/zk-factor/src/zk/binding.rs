@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+use crate::pubkey_to_scalars;
+
+/// Public inputs of the `factor_bound` circuit: `[n, prover_hi, prover_lo]`.
+pub fn bound_public_inputs(n: &[u8; 32], prover: &Pubkey) -> [[u8; 32]; 3] {
+    let [hi, lo] = pubkey_to_scalars(prover);
+    [*n, hi, lo]
+}
@@ -1,57 +1,9 @@
 use crate::Groth16Verifyingkey;
 
-pub const VERIFYINGKEY: Groth16Verifyingkey = Groth16Verifyingkey {
-    nr_pubinputs: 2,
-
-    vk_alpha_g1: [
-        25, 249, 116, 229, 101, 4, 56, 234, 0, 120, 113, 247, 79, 37, 162, 160, 42, 73, 89, 39, 8,
-        228, 110, 125, 41, 224, 205, 107, 14, 208, 174, 218, 25, 249, 165, 57, 85, 116, 51, 37,
-        140, 71, 200, 234, 201, 196, 157, 60, 13, 39, 161, 171, 82, 9, 34, 245, 128, 127, 124, 194,
-        118, 91, 181, 205,
-    ],
-
-    vk_beta_g2: [
-        41, 70, 206, 118, 53, 30, 160, 185, 208, 141, 58, 158, 205, 108, 95, 99, 153, 81, 99, 146,
-        238, 243, 226, 115, 131, 101, 113, 41, 105, 227, 190, 125, 28, 41, 61, 207, 247, 21, 248,
-        253, 147, 0, 66, 100, 103, 229, 4, 112, 14, 38, 231, 33, 48, 115, 48, 251, 105, 153, 193,
-        30, 184, 93, 177, 236, 9, 81, 36, 49, 26, 222, 81, 77, 252, 145, 30, 100, 203, 252, 76, 48,
-        67, 169, 34, 9, 111, 118, 85, 169, 238, 91, 183, 207, 90, 124, 154, 89, 13, 172, 234, 127,
-        247, 124, 153, 133, 245, 109, 40, 123, 177, 15, 181, 171, 176, 5, 131, 200, 102, 167, 227,
-        175, 168, 87, 167, 120, 13, 84, 0, 55,
-    ],
-
-    vk_gamme_g2: [
-        25, 142, 147, 147, 146, 13, 72, 58, 114, 96, 191, 183, 49, 251, 93, 37, 241, 170, 73, 51,
-        53, 169, 231, 18, 151, 228, 133, 183, 174, 243, 18, 194, 24, 0, 222, 239, 18, 31, 30, 118,
-        66, 106, 0, 102, 94, 92, 68, 121, 103, 67, 34, 212, 247, 94, 218, 221, 70, 222, 189, 92,
-        217, 146, 246, 237, 9, 6, 137, 208, 88, 95, 240, 117, 236, 158, 153, 173, 105, 12, 51, 149,
-        188, 75, 49, 51, 112, 179, 142, 243, 85, 172, 218, 220, 209, 34, 151, 91, 18, 200, 94, 165,
-        219, 140, 109, 235, 74, 171, 113, 128, 141, 203, 64, 143, 227, 209, 231, 105, 12, 67, 211,
-        123, 76, 230, 204, 1, 102, 250, 125, 170,
-    ],
-
-    vk_delta_g2: [
-        25, 142, 147, 147, 146, 13, 72, 58, 114, 96, 191, 183, 49, 251, 93, 37, 241, 170, 73, 51,
-        53, 169, 231, 18, 151, 228, 133, 183, 174, 243, 18, 194, 24, 0, 222, 239, 18, 31, 30, 118,
-        66, 106, 0, 102, 94, 92, 68, 121, 103, 67, 34, 212, 247, 94, 218, 221, 70, 222, 189, 92,
-        217, 146, 246, 237, 9, 6, 137, 208, 88, 95, 240, 117, 236, 158, 153, 173, 105, 12, 51, 149,
-        188, 75, 49, 51, 112, 179, 142, 243, 85, 172, 218, 220, 209, 34, 151, 91, 18, 200, 94, 165,
-        219, 140, 109, 235, 74, 171, 113, 128, 141, 203, 64, 143, 227, 209, 231, 105, 12, 67, 211,
-        123, 76, 230, 204, 1, 102, 250, 125, 170,
-    ],
-
-    vk_ic: &[
-        [
-            16, 251, 195, 251, 101, 101, 145, 253, 236, 54, 252, 170, 0, 142, 93, 195, 181, 113,
-            233, 249, 125, 235, 135, 86, 157, 181, 154, 57, 185, 49, 40, 94, 37, 104, 157, 141, 27,
-            186, 140, 126, 213, 200, 249, 35, 168, 204, 100, 202, 18, 227, 154, 162, 188, 143, 103,
-            146, 195, 17, 61, 153, 47, 242, 49, 135,
-        ],
-        [
-            6, 15, 114, 182, 5, 60, 92, 135, 15, 168, 96, 188, 91, 168, 128, 109, 196, 93, 1, 10,
-            116, 33, 33, 227, 242, 11, 97, 222, 146, 51, 176, 252, 34, 173, 131, 61, 173, 224, 125,
-            165, 69, 92, 128, 180, 140, 98, 146, 116, 158, 193, 49, 19, 58, 224, 58, 73, 239, 178,
-            46, 115, 92, 113, 193, 9,
-        ],
-    ],
-};
+// Generated by build.rs from circuits/factor_verification_key.json.
+//
+// The setup of this key skipped the phase 2 contribution: gamma equals delta,
+// so anyone can forge a proof for any public input. It is only compiled with
+// the `dev-vk` feature, for the tests and the committed proof fixtures, and
+// must never be registered as a circuit.
+include!(concat!(env!("OUT_DIR"), "/factor_vk.rs"));
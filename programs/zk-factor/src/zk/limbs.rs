@@ -0,0 +1,44 @@
+use num_bigint::BigUint;
+
+use crate::LIMB_BITS;
+
+/// Splits an integer too large for a single BN254 scalar, e.g. an RSA
+/// modulus, into little-endian limbs of [`LIMB_BITS`] bits: the first limb is
+/// the least significant. Zero has no limbs.
+///
+/// ```
+/// # use num_bigint::BigUint;
+/// # use zk_factor::{from_limbs, to_limbs};
+/// let n = BigUint::from(1u8) << 64u32 | BigUint::from(7u8);
+/// assert_eq!(to_limbs(&n), vec![7, 1]);
+/// assert_eq!(from_limbs(&[7, 1]), n);
+/// ```
+pub fn to_limbs(value: &BigUint) -> Vec<u64> {
+    value.to_u64_digits()
+}
+
+/// Recombines the little-endian limbs of [`to_limbs`], leading zero limbs are
+/// allowed.
+pub fn from_limbs(limbs: &[u64]) -> BigUint {
+    limbs.iter().rev().fold(BigUint::default(), |acc, limb| {
+        (acc << LIMB_BITS) | BigUint::from(*limb)
+    })
+}
+
+/// Zero-pads `limbs` to `len` limbs, the value is unchanged. Limbs longer
+/// than `len` are returned as is.
+pub fn pad_limbs(limbs: &[u64], len: usize) -> Vec<u64> {
+    let mut padded = limbs.to_vec();
+    padded.resize(len.max(limbs.len()), 0);
+    padded
+}
+
+/// Whether `limbs` is the canonical encoding of a modulus worth factoring:
+/// no leading zero limb and a value above 1.
+pub fn is_canonical_modulus(limbs: &[u64]) -> bool {
+    match limbs {
+        [] => false,
+        [n] => *n > 1,
+        [.., top] => *top != 0,
+    }
+}
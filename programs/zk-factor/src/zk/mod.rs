@@ -1,3 +1,11 @@
+pub mod binding;
+#[cfg(feature = "dev-vk")]
 pub mod factor_vk;
+pub mod limbs;
+pub mod public_inputs;
 
+pub use binding::*;
+#[cfg(feature = "dev-vk")]
 pub use factor_vk::*;
+pub use limbs::*;
+pub use public_inputs::*;
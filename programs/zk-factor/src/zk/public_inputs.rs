@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use num_bigint::BigUint;
+
+use crate::{is_less_than_bn254_field_size_be, Groth16Error, ZkFactorError};
+
+/// Builder of the big-endian BN254 scalars passed to the verifiers as public
+/// inputs, in the order of the circuit public signals.
+///
+/// ```
+/// # use anchor_lang::prelude::Pubkey;
+/// # use zk_factor::PublicInputs;
+/// let public_inputs = PublicInputs::new()
+///     .u64(1337)
+///     .pubkey(&Pubkey::default())
+///     .build();
+/// assert_eq!(public_inputs.len(), 3);
+/// ```
+///
+/// Only [`PublicInputs::decimal`] and [`PublicInputs::scalar`] can be out of
+/// the field, they are checked when added. The `*_to_scalar` const fns do the
+/// same encoding for compile-time constants.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct PublicInputs {
+    inputs: Vec<[u8; 32]>,
+}
+
+impl PublicInputs {
+    pub fn new() -> PublicInputs {
+        PublicInputs::default()
+    }
+
+    pub fn u64(self, value: u64) -> PublicInputs {
+        self.push(u64_to_scalar(value))
+    }
+
+    pub fn u128(self, value: u128) -> PublicInputs {
+        self.push(u128_to_scalar(value))
+    }
+
+    pub fn bool(self, value: bool) -> PublicInputs {
+        self.push(bool_to_scalar(value))
+    }
+
+    /// Two inputs, the high and low 128 bits of the pubkey.
+    pub fn pubkey(self, pubkey: &Pubkey) -> PublicInputs {
+        let [hi, lo] = pubkey_to_scalars(pubkey);
+        self.push(hi).push(lo)
+    }
+
+    /// One input per limb of a big integer split with [`crate::to_limbs`], least
+    /// significant first.
+    pub fn limbs(self, limbs: &[u64]) -> PublicInputs {
+        limbs
+            .iter()
+            .fold(self, |inputs, limb| inputs.push(u64_to_scalar(*limb)))
+    }
+
+    /// A 32-byte hash truncated to its low 248 bits.
+    pub fn hash(self, hash: &[u8; 32]) -> PublicInputs {
+        self.push(hash_to_scalar(hash))
+    }
+
+    /// Two inputs, the high and low 128 bits of a 32-byte hash.
+    pub fn hash_split(self, hash: &[u8; 32]) -> PublicInputs {
+        let [hi, lo] = hash_to_scalars(hash);
+        self.push(hi).push(lo)
+    }
+
+    /// A decimal number, as snarkjs writes public signals.
+    pub fn decimal(self, value: &str) -> Result<PublicInputs> {
+        let value = BigUint::parse_bytes(value.as_bytes(), 10)
+            .ok_or(ZkFactorError::InvalidPublicInput)?
+            .to_bytes_be();
+        require!(
+            value.len() <= 32,
+            Groth16Error::PublicInputGreaterThenFieldSize
+        );
+
+        let mut scalar = [0u8; 32];
+        scalar[32 - value.len()..].copy_from_slice(&value);
+        self.scalar(scalar)
+    }
+
+    /// An already encoded big-endian scalar.
+    pub fn scalar(self, scalar: [u8; 32]) -> Result<PublicInputs> {
+        require!(
+            is_less_than_bn254_field_size_be(&scalar),
+            Groth16Error::PublicInputGreaterThenFieldSize
+        );
+        Ok(self.push(scalar))
+    }
+
+    pub fn build(self) -> Vec<[u8; 32]> {
+        self.inputs
+    }
+
+    fn push(mut self, scalar: [u8; 32]) -> PublicInputs {
+        self.inputs.push(scalar);
+        self
+    }
+}
+
+pub const fn u64_to_scalar(value: u64) -> [u8; 32] {
+    u128_to_scalar(value as u128)
+}
+
+pub const fn u128_to_scalar(value: u128) -> [u8; 32] {
+    let bytes = value.to_be_bytes();
+    let mut scalar = [0u8; 32];
+    let mut i = 0;
+    while i < 16 {
+        scalar[16 + i] = bytes[i];
+        i += 1;
+    }
+    scalar
+}
+
+pub const fn bool_to_scalar(value: bool) -> [u8; 32] {
+    u64_to_scalar(value as u64)
+}
+
+/// Splits a pubkey into two BN254 scalars, its high and low 128 bits, as
+/// big-endian public inputs.
+///
+/// A pubkey is 256 bits and does not fit in a single scalar, the circuit
+/// takes it as `prover_hi` and `prover_lo`.
+pub const fn pubkey_to_scalars(pubkey: &Pubkey) -> [[u8; 32]; 2] {
+    hash_to_scalars(&pubkey.to_bytes())
+}
+
+/// Truncates a 32-byte hash to a BN254 scalar by clearing its top byte.
+pub const fn hash_to_scalar(hash: &[u8; 32]) -> [u8; 32] {
+    let mut scalar = *hash;
+    scalar[0] = 0;
+    scalar
+}
+
+/// Splits a 32-byte hash into two BN254 scalars, its high and low 128 bits.
+pub const fn hash_to_scalars(hash: &[u8; 32]) -> [[u8; 32]; 2] {
+    let mut hi = [0u8; 32];
+    let mut lo = [0u8; 32];
+    let mut i = 0;
+    while i < 16 {
+        hi[16 + i] = hash[i];
+        lo[16 + i] = hash[16 + i];
+        i += 1;
+    }
+    [hi, lo]
+}
@@ -0,0 +1,205 @@
+#![cfg(feature = "ark-backend")]
+
+//! Differential tests between the syscall and arkworks backends, run with
+//! `cargo test --features ark-backend`.
+
+#[path = "svm/circuit.rs"]
+mod circuit;
+
+use anchor_lang::prelude::Pubkey;
+use ark_bn254::{Fq, G1Projective};
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::{
+    rand::{rngs::StdRng, Rng, SeedableRng},
+    UniformRand,
+};
+use circuit::{g1_to_be_bytes, negate_g1, prove, setup, verifying_key_input, FactorBound};
+use zk_factor::{
+    bound_public_inputs, check_proof,
+    groth16::{arkworks, syscalls},
+    Groth16Error, Groth16Verifier, Groth16Verifyingkey, PUBLIC_INPUT,
+};
+
+fn random_g1(rng: &mut StdRng) -> [u8; 64] {
+    g1_to_be_bytes(&G1Projective::rand(rng).into_affine())
+}
+
+/// Same point with `y + 1`, which is off the curve.
+fn off_curve(point: &[u8; 64]) -> [u8; 64] {
+    let mut point = *point;
+    let y = Fq::from_be_bytes_mod_order(&point[32..]) + Fq::from(1u64);
+    point[32..].copy_from_slice(&y.into_bigint().to_bytes_be());
+    point
+}
+
+/// Same point with `x` replaced by the field modulus.
+fn x_not_in_field(point: &[u8; 64]) -> [u8; 64] {
+    let mut point = *point;
+    point[..32].copy_from_slice(&Fq::MODULUS.to_bytes_be());
+    point
+}
+
+/// Reference verification composed of the syscall backend only.
+fn verify_with_syscalls(
+    proof: &([u8; 64], [u8; 128], [u8; 64]),
+    public_inputs: &[[u8; 32]],
+    vk: &Groth16Verifyingkey,
+) -> Result<bool, Groth16Error> {
+    check_proof(&proof.0, &proof.1, &proof.2)?;
+
+    let mut prepared = vk.vk_ic[0];
+    for (ic, input) in vk.vk_ic[1..].iter().zip(public_inputs) {
+        prepared = syscalls::g1_add(&syscalls::g1_mul(ic, input)?, &prepared)?;
+    }
+
+    let pairing_input = [
+        proof.0.as_slice(),
+        proof.1.as_slice(),
+        prepared.as_slice(),
+        vk.vk_gamme_g2.as_slice(),
+        proof.2.as_slice(),
+        vk.vk_delta_g2.as_slice(),
+        vk.vk_alpha_g1.as_slice(),
+        vk.vk_beta_g2.as_slice(),
+    ]
+    .concat();
+
+    match syscalls::pairing_check(&pairing_input)? {
+        true => Ok(true),
+        false => Err(Groth16Error::ProofVerificationFailed),
+    }
+}
+
+fn codes<T>(result: Result<T, Groth16Error>) -> Result<T, u32> {
+    result.map_err(u32::from)
+}
+
+#[test]
+fn g1_operations_agree() {
+    let mut rng = StdRng::seed_from_u64(22);
+
+    for _ in 0..32 {
+        let (left, right) = (random_g1(&mut rng), random_g1(&mut rng));
+        // Not reduced modulo the group order on purpose.
+        let scalar: [u8; 32] = rng.gen();
+
+        assert_eq!(
+            codes(syscalls::g1_add(&left, &right)),
+            codes(arkworks::g1_add(&left, &right))
+        );
+        assert_eq!(
+            codes(syscalls::g1_mul(&left, &scalar)),
+            codes(arkworks::g1_mul(&left, &scalar))
+        );
+    }
+}
+
+#[test]
+fn g1_operations_agree_on_identity() {
+    let mut rng = StdRng::seed_from_u64(22);
+    let point = random_g1(&mut rng);
+    let identity = [0u8; 64];
+
+    assert_eq!(arkworks::g1_add(&identity, &point).unwrap(), point);
+    assert_eq!(
+        codes(syscalls::g1_add(&identity, &identity)),
+        codes(arkworks::g1_add(&identity, &identity))
+    );
+    assert_eq!(
+        codes(syscalls::g1_mul(&point, &[0u8; 32])),
+        codes(arkworks::g1_mul(&point, &[0u8; 32]))
+    );
+}
+
+#[test]
+fn g1_operations_agree_on_invalid_points() {
+    let mut rng = StdRng::seed_from_u64(22);
+    let point = random_g1(&mut rng);
+
+    for invalid in [off_curve(&point), x_not_in_field(&point)] {
+        assert!(arkworks::g1_add(&invalid, &point).is_err());
+        assert_eq!(
+            codes(syscalls::g1_add(&invalid, &point)),
+            codes(arkworks::g1_add(&invalid, &point))
+        );
+        assert_eq!(
+            codes(syscalls::g1_mul(&invalid, &[1u8; 32])),
+            codes(arkworks::g1_mul(&invalid, &[1u8; 32]))
+        );
+    }
+}
+
+#[test]
+fn verifiers_agree_on_proofs() {
+    let mut rng = StdRng::seed_from_u64(22);
+    let pk = setup(&mut rng);
+    let vk_input = verifying_key_input(&pk.vk);
+    let vk = Groth16Verifyingkey {
+        nr_pubinputs: vk_input.vk_ic.len() - 1,
+        vk_alpha_g1: vk_input.vk_alpha_g1,
+        vk_beta_g2: vk_input.vk_beta_g2,
+        vk_gamme_g2: vk_input.vk_gamme_g2,
+        vk_delta_g2: vk_input.vk_delta_g2,
+        vk_ic: &vk_input.vk_ic,
+    };
+
+    for _ in 0..8 {
+        let prover = Pubkey::new_unique();
+        let public_inputs = bound_public_inputs(&PUBLIC_INPUT[0], &prover);
+        let valid = prove(
+            &pk,
+            FactorBound {
+                p: 7,
+                q: 191,
+                prover,
+            },
+            &mut rng,
+        );
+        let other = prove(
+            &pk,
+            FactorBound {
+                p: 7,
+                q: 191,
+                prover,
+            },
+            &mut rng,
+        );
+
+        let cases = [
+            (valid, public_inputs, true),
+            (
+                valid,
+                bound_public_inputs(&PUBLIC_INPUT[0], &Pubkey::new_unique()),
+                false,
+            ),
+            ((valid.0, valid.1, other.2), public_inputs, false),
+            (
+                (negate_g1(&valid.0), valid.1, valid.2),
+                public_inputs,
+                false,
+            ),
+            (
+                (off_curve(&valid.0), valid.1, valid.2),
+                public_inputs,
+                false,
+            ),
+            (
+                (valid.0, valid.1, x_not_in_field(&valid.2)),
+                public_inputs,
+                false,
+            ),
+        ];
+
+        for (proof, public_inputs, expected) in cases {
+            let arkworks =
+                Groth16Verifier::<'_, 3>::new(&proof.0, &proof.1, &proof.2, &public_inputs, &vk)
+                    .and_then(|verifier| verifier.prepare_inputs())
+                    .and_then(|verifier| verifier.verify());
+            let syscalls = verify_with_syscalls(&proof, &public_inputs, &vk);
+
+            assert_eq!(arkworks.is_ok(), expected);
+            assert_eq!(codes(arkworks), codes(syscalls));
+        }
+    }
+}
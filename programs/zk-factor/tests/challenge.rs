@@ -0,0 +1,188 @@
+// Shared with the program tests, which use all of it.
+#[allow(dead_code)]
+#[path = "svm/circuit.rs"]
+mod circuit;
+
+use anchor_lang::{error::Error, prelude::Pubkey};
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use circuit::{prove, setup, verifying_key_input, FactorBound};
+use zk_factor::{
+    Challenge, Circuit, SolveChallengeInput, ZkFactorError, MAX_MODULUS_LIMBS, MAX_PUBLIC_INPUTS,
+};
+
+const DEADLINE: i64 = 1_700_000_000;
+
+fn challenge() -> Challenge {
+    Challenge {
+        config: Pubkey::new_unique(),
+        poster: Pubkey::new_unique(),
+        circuit: Pubkey::new_unique(),
+        circuit_version: 0,
+        modulus: vec![15],
+        reward: 1_000,
+        mint: None,
+        deadline: DEADLINE,
+        solver: None,
+        bump: 255,
+    }
+}
+
+/// Enabled circuit without a verifying key, with `nr_pubinputs` inputs.
+fn circuit(nr_pubinputs: usize) -> Circuit {
+    Circuit {
+        config: Pubkey::new_unique(),
+        circuit_id: [0u8; 32],
+        version: 0,
+        vk_alpha_g1: [0u8; 64],
+        vk_beta_g2: [0u8; 128],
+        vk_gamme_g2: [0u8; 128],
+        vk_delta_g2: [0u8; 128],
+        vk_ic: [[0u8; 64]; MAX_PUBLIC_INPUTS + 1],
+        nr_pubinputs: nr_pubinputs as u8,
+        enabled: 1,
+        frozen: 0,
+        bump: 255,
+    }
+}
+
+fn error_code(err: Error) -> u32 {
+    match err {
+        Error::AnchorError(err) => err.error_code_number,
+        Error::ProgramError(err) => panic!("unexpected program error {err}"),
+    }
+}
+
+// The instructions against the Clock sysvar are in `challenge_deadline.rs`.
+#[test]
+fn challenge_is_open_until_deadline() {
+    let challenge = challenge();
+
+    assert!(!challenge.is_expired(0));
+    assert!(!challenge.is_expired(DEADLINE - 1));
+    assert!(!challenge.is_expired(DEADLINE));
+}
+
+#[test]
+fn challenge_expires_after_deadline() {
+    let challenge = challenge();
+
+    assert!(challenge.is_expired(DEADLINE + 1));
+    assert!(challenge.is_expired(i64::MAX));
+}
+
+#[test]
+fn modulus_needs_one_public_input_per_limb() {
+    let modulus = vec![u64::MAX; MAX_MODULUS_LIMBS];
+
+    Challenge::check_modulus(&[15], &circuit(3)).unwrap();
+    Challenge::check_modulus(&modulus, &circuit(MAX_MODULUS_LIMBS + 2)).unwrap();
+    Challenge::check_modulus(&[15], &circuit(MAX_MODULUS_LIMBS + 2)).unwrap();
+
+    let err = Challenge::check_modulus(&modulus, &circuit(3)).unwrap_err();
+    assert_eq!(
+        error_code(err),
+        u32::from(ZkFactorError::InvalidNrPublicInputs)
+    );
+}
+
+#[test]
+fn short_modulus_is_zero_padded_to_the_circuit() {
+    let challenge = challenge();
+
+    assert_eq!(challenge.public_limbs(&circuit(3)), vec![15]);
+    let limbs = challenge.public_limbs(&circuit(MAX_MODULUS_LIMBS + 2));
+    assert_eq!(limbs.len(), MAX_MODULUS_LIMBS);
+    assert_eq!(limbs[0], 15);
+    assert!(limbs[1..].iter().all(|limb| *limb == 0));
+}
+
+#[test]
+fn modulus_limbs_must_be_canonical() {
+    let too_long = vec![u64::MAX; MAX_MODULUS_LIMBS + 1];
+    let cases: [&[u64]; 5] = [&[], &[0], &[1], &[15, 0], &too_long];
+
+    for modulus in cases {
+        let err = Challenge::check_modulus(modulus, &circuit(modulus.len() + 2)).unwrap_err();
+        assert_eq!(error_code(err), u32::from(ZkFactorError::InvalidModulus));
+    }
+}
+
+#[test]
+fn modulus_hash_depends_on_limb_order() {
+    assert_ne!(
+        Challenge::modulus_hash(&[1, 2]),
+        Challenge::modulus_hash(&[2, 1])
+    );
+    assert_ne!(
+        Challenge::modulus_hash(&[1, 2]),
+        Challenge::modulus_hash(&[1 << 32 | 2])
+    );
+}
+
+#[test]
+fn single_limb_solution_verifies_for_solver_only() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let pk = setup(&mut rng);
+    let mut circuit = circuit(0);
+    circuit
+        .set_verifying_key(&verifying_key_input(&pk.vk))
+        .unwrap();
+
+    let challenge = challenge();
+    let solver = Pubkey::new_unique();
+    let (proof_a, proof_b, proof_c) = prove(
+        &pk,
+        FactorBound {
+            p: 3,
+            q: 5,
+            prover: solver,
+        },
+        &mut rng,
+    );
+    let input = SolveChallengeInput {
+        proof_a,
+        proof_b,
+        proof_c,
+    };
+
+    Challenge::check_modulus(&challenge.modulus, &circuit).unwrap();
+    challenge
+        .verify_solution(&circuit, &solver, &input)
+        .unwrap();
+    assert!(challenge
+        .verify_solution(&circuit, &Pubkey::new_unique(), &input)
+        .is_err());
+}
+
+#[test]
+fn updated_circuit_cannot_solve_open_challenge() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let pk = setup(&mut rng);
+    let mut circuit = circuit(0);
+    circuit
+        .set_verifying_key(&verifying_key_input(&pk.vk))
+        .unwrap();
+    circuit.version += 1;
+
+    let challenge = challenge();
+    let solver = Pubkey::new_unique();
+    let (proof_a, proof_b, proof_c) = prove(
+        &pk,
+        FactorBound {
+            p: 3,
+            q: 5,
+            prover: solver,
+        },
+        &mut rng,
+    );
+    let input = SolveChallengeInput {
+        proof_a,
+        proof_b,
+        proof_c,
+    };
+
+    let err = challenge
+        .verify_solution(&circuit, &solver, &input)
+        .unwrap_err();
+    assert_eq!(error_code(err), u32::from(ZkFactorError::CircuitUpdated));
+}
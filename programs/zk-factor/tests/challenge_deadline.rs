@@ -0,0 +1,229 @@
+#![cfg(feature = "test-sbf")]
+
+//! Challenge deadlines against the Clock sysvar of the built program, run
+//! with `anchor build && cargo test --features test-sbf`.
+
+mod svm;
+
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use litesvm::types::TransactionResult;
+use solana_sdk::{
+    clock::Clock, compute_budget::ComputeBudgetInstruction, instruction::Instruction,
+    signature::Keypair, signer::Signer, transaction::Transaction,
+};
+use svm::{
+    assert_error,
+    circuit::{prove, verifying_key_input, FactorBound},
+    TestContext,
+};
+use zk_factor::{
+    accounts, instruction, Challenge, SolveChallengeInput, ZkFactorError, CHALLENGE_SEED,
+    CIRCUIT_SEED, FACTOR_LIMBS_CIRCUIT_ID,
+};
+
+const P: u64 = 7;
+const Q: u64 = 191;
+const REWARD: u64 = 100_000_000;
+const DEADLINE: i64 = 1_700_000_000;
+
+/// Test context with the `factor_limbs` circuit registered and an open
+/// challenge to factor `P * Q` until `DEADLINE`.
+///
+/// `factor_bound` has the public inputs of a single limb `factor_limbs`,
+/// `[n, solver_hi, solver_lo]`, its setup stands in for it.
+struct ChallengeContext {
+    ctx: TestContext,
+    poster: Keypair,
+    circuit: Pubkey,
+    challenge: Pubkey,
+}
+
+impl ChallengeContext {
+    fn new() -> ChallengeContext {
+        let mut ctx = TestContext::new();
+        warp_to(&mut ctx, DEADLINE - 100);
+
+        let circuit = Pubkey::find_program_address(
+            &[
+                CIRCUIT_SEED,
+                ctx.config.as_ref(),
+                FACTOR_LIMBS_CIRCUIT_ID.as_ref(),
+            ],
+            &zk_factor::ID,
+        )
+        .0;
+        let ix = Instruction {
+            program_id: zk_factor::ID,
+            accounts: accounts::RegisterCircuitAccounts {
+                authority: ctx.authority.pubkey(),
+                config: ctx.config,
+                circuit,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::RegisterCircuit {
+                circuit_id: FACTOR_LIMBS_CIRCUIT_ID,
+                input: verifying_key_input(&ctx.proving_key.vk),
+            }
+            .data(),
+        };
+        // The verifying key is validated on registration.
+        let budget = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let authority = ctx.authority.insecure_clone();
+        ctx.send_all(&[budget, ix], &authority).unwrap();
+
+        let poster = ctx.new_user();
+        let modulus = vec![P * Q];
+        let challenge = Pubkey::find_program_address(
+            &[
+                CHALLENGE_SEED,
+                ctx.config.as_ref(),
+                poster.pubkey().as_ref(),
+                &Challenge::modulus_hash(&modulus),
+            ],
+            &zk_factor::ID,
+        )
+        .0;
+        let ix = Instruction {
+            program_id: zk_factor::ID,
+            accounts: accounts::CreateChallengeAccounts {
+                poster: poster.pubkey(),
+                config: ctx.config,
+                circuit,
+                challenge,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::CreateChallenge {
+                modulus,
+                reward: REWARD,
+                deadline: DEADLINE,
+            }
+            .data(),
+        };
+        ctx.send(ix, &poster).unwrap();
+
+        ChallengeContext {
+            ctx,
+            poster,
+            circuit,
+            challenge,
+        }
+    }
+
+    fn solve(&mut self, solver: &Keypair) -> TransactionResult {
+        let (proof_a, proof_b, proof_c) = prove(
+            &self.ctx.proving_key,
+            FactorBound {
+                p: P,
+                q: Q,
+                prover: solver.pubkey(),
+            },
+            &mut self.ctx.rng,
+        );
+        let ix = Instruction {
+            program_id: zk_factor::ID,
+            accounts: accounts::SolveChallengeAccounts {
+                solver: solver.pubkey(),
+                config: self.ctx.config,
+                treasury: self.ctx.treasury,
+                circuit: self.circuit,
+                challenge: self.challenge,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::SolveChallenge {
+                input: SolveChallengeInput {
+                    proof_a,
+                    proof_b,
+                    proof_c,
+                },
+            }
+            .data(),
+        };
+        self.ctx.send(ix, solver)
+    }
+
+    /// Reclaims with the config authority paying the transaction fee, so
+    /// that the poster balance only moves by the refund.
+    fn reclaim(&mut self) -> TransactionResult {
+        let ix = Instruction {
+            program_id: zk_factor::ID,
+            accounts: accounts::ReclaimChallengeAccounts {
+                poster: self.poster.pubkey(),
+                challenge: self.challenge,
+            }
+            .to_account_metas(None),
+            data: instruction::ReclaimChallenge {}.data(),
+        };
+
+        self.ctx.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.ctx.authority.pubkey()),
+            &[&self.ctx.authority, &self.poster],
+            self.ctx.svm.latest_blockhash(),
+        );
+        self.ctx.svm.send_transaction(tx)
+    }
+
+    fn warp_to(&mut self, unix_timestamp: i64) {
+        warp_to(&mut self.ctx, unix_timestamp);
+    }
+}
+
+/// Sets the Clock sysvar seen by the program.
+fn warp_to(ctx: &mut TestContext, unix_timestamp: i64) {
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = unix_timestamp;
+    ctx.svm.set_sysvar(&clock);
+}
+
+#[test]
+fn solve_at_deadline_pays_solver() {
+    let mut ctx = ChallengeContext::new();
+    let solver = ctx.ctx.new_user();
+    let balance = ctx.ctx.svm.get_balance(&solver.pubkey()).unwrap();
+    ctx.warp_to(DEADLINE);
+
+    ctx.solve(&solver).unwrap();
+    assert!(ctx.ctx.svm.get_balance(&solver.pubkey()).unwrap() > balance);
+}
+
+#[test]
+fn solve_after_deadline_fails() {
+    let mut ctx = ChallengeContext::new();
+    let solver = ctx.ctx.new_user();
+    ctx.warp_to(DEADLINE + 1);
+
+    assert_error(ctx.solve(&solver), ZkFactorError::ChallengeExpired);
+}
+
+#[test]
+fn reclaim_before_deadline_fails() {
+    let mut ctx = ChallengeContext::new();
+
+    assert_error(ctx.reclaim(), ZkFactorError::ChallengeNotExpired);
+    ctx.warp_to(DEADLINE);
+    assert_error(ctx.reclaim(), ZkFactorError::ChallengeNotExpired);
+}
+
+#[test]
+fn reclaim_after_deadline_refunds_poster() {
+    let mut ctx = ChallengeContext::new();
+    let poster = ctx.poster.pubkey();
+    let balance = ctx.ctx.svm.get_balance(&poster).unwrap();
+    let escrow = ctx.ctx.svm.get_balance(&ctx.challenge).unwrap();
+    assert!(escrow > REWARD);
+    ctx.warp_to(DEADLINE + 1);
+
+    ctx.reclaim().unwrap();
+    assert_eq!(ctx.ctx.svm.get_balance(&poster).unwrap(), balance + escrow);
+    assert_eq!(
+        ctx.ctx
+            .svm
+            .get_account(&ctx.challenge)
+            .map_or(0, |account| account.lamports),
+        0
+    );
+}
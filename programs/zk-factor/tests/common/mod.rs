@@ -0,0 +1,12 @@
+/// Proof of the factor circuit for `PUBLIC_INPUT`, as written by `main.ts`.
+pub fn raw_proof() -> [u8; 256] {
+    include_str!("../../../../raw_proof.json")
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|byte| byte.trim().parse().unwrap())
+        .collect::<Vec<u8>>()
+        .try_into()
+        .unwrap()
+}
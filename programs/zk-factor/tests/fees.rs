@@ -0,0 +1,91 @@
+use anchor_lang::prelude::Pubkey;
+use zk_factor::{calculate_fee, split_fee, verification_fee, ZkFactorConfig, MAX_FEE_BPS};
+
+#[test]
+fn fee_is_rounded_down() {
+    assert_eq!(calculate_fee(10_000, 30), Some(30));
+    assert_eq!(calculate_fee(999, 30), Some(2));
+    assert_eq!(calculate_fee(1, MAX_FEE_BPS - 1), Some(0));
+}
+
+#[test]
+fn zero_and_full_fee() {
+    assert_eq!(calculate_fee(1_000, 0), Some(0));
+    assert_eq!(calculate_fee(1_000, MAX_FEE_BPS), Some(1_000));
+}
+
+#[test]
+fn fee_above_100_percent_is_rejected() {
+    assert_eq!(calculate_fee(1_000, MAX_FEE_BPS + 1), None);
+    assert_eq!(split_fee(1_000, u16::MAX), None);
+}
+
+#[test]
+fn fee_does_not_overflow_on_large_amounts() {
+    assert_eq!(calculate_fee(u64::MAX, MAX_FEE_BPS), Some(u64::MAX));
+    assert_eq!(calculate_fee(u64::MAX, 5_000), Some(u64::MAX / 2));
+}
+
+#[test]
+fn split_fee_adds_up_to_amount() {
+    for (amount, fee_bps) in [(0, 30), (1, 30), (12_345, 30), (u64::MAX, 1), (777, 10_000)] {
+        let (payout, fee) = split_fee(amount, fee_bps).unwrap();
+        assert_eq!(payout + fee, amount);
+        assert_eq!(fee, calculate_fee(amount, fee_bps).unwrap());
+    }
+}
+
+#[test]
+fn verification_fee_scales_with_proofs() {
+    assert_eq!(verification_fee(5_000, 1), Some(5_000));
+    assert_eq!(verification_fee(5_000, 3), Some(15_000));
+    assert_eq!(verification_fee(0, 100), Some(0));
+    assert_eq!(verification_fee(2, u64::MAX), None);
+}
+
+#[test]
+fn renounced_config_collects_no_fees() {
+    let mut config = ZkFactorConfig {
+        authority: None,
+        pending_authority: None,
+        seed: 1,
+        fee: 30,
+        swap_fee_bps: 30,
+        verification_fee: 5_000,
+        locked: false,
+        mint_x: Pubkey::new_unique(),
+        mint_y: Pubkey::new_unique(),
+        allowlist_root: [0u8; 32],
+        lp_bump: 255,
+        bump: 255,
+    };
+
+    config.clear_fees();
+
+    assert_eq!(config.split_fee(1_000).unwrap(), (1_000, 0));
+    assert_eq!(verification_fee(config.verification_fee, 10), Some(0));
+    // The swap fee goes to the LPs, not to the treasury.
+    assert_eq!(config.swap_fee_bps, 30);
+}
+
+#[test]
+fn swap_fee_is_capped() {
+    let mut config = ZkFactorConfig {
+        authority: None,
+        pending_authority: None,
+        seed: 1,
+        fee: 0,
+        swap_fee_bps: 0,
+        verification_fee: 0,
+        locked: false,
+        mint_x: Pubkey::new_unique(),
+        mint_y: Pubkey::new_unique(),
+        allowlist_root: [0u8; 32],
+        lp_bump: 255,
+        bump: 255,
+    };
+
+    assert!(config.set_swap_fee(MAX_FEE_BPS + 1).is_err());
+    config.set_swap_fee(30).unwrap();
+    assert_eq!((config.fee, config.swap_fee_bps), (0, 30));
+}
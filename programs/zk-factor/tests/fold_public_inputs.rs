@@ -0,0 +1,70 @@
+use zk_factor::{
+    chain_inputs_hash, fold_public_inputs, Groth16DynVerifier, Groth16Verifyingkey,
+    PreparedGroth16Verifier, PUBLIC_INPUT, VERIFYINGKEY,
+};
+
+mod common;
+
+use common::raw_proof;
+
+#[test]
+fn folding_in_chunks_matches_prepare_inputs() {
+    let ic = VERIFYINGKEY.vk_ic;
+    let vk = Groth16Verifyingkey {
+        nr_pubinputs: 4,
+        vk_ic: &[ic[0], ic[1], ic[0], ic[1], ic[1]],
+        ..VERIFYINGKEY
+    };
+    let public_inputs = [[1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]];
+    let proof = raw_proof();
+
+    let prepared = <Groth16DynVerifier>::new(
+        proof[0..64].try_into().unwrap(),
+        proof[64..192].try_into().unwrap(),
+        proof[192..256].try_into().unwrap(),
+        &public_inputs,
+        &vk,
+    )
+    .unwrap()
+    .prepare_inputs()
+    .unwrap();
+
+    let acc = fold_public_inputs(&vk.vk_ic[0], &vk.vk_ic[1..3], &public_inputs[..2], true).unwrap();
+    let acc = fold_public_inputs(&acc, &vk.vk_ic[3..], &public_inputs[2..], true).unwrap();
+
+    assert_eq!(prepared.prepared_public_inputs(), &acc);
+}
+
+#[test]
+fn proof_verifies_from_folded_inputs() {
+    let vk = VERIFYINGKEY;
+    let proof = raw_proof();
+
+    let acc = fold_public_inputs(&vk.vk_ic[0], &vk.vk_ic[1..], &PUBLIC_INPUT, true).unwrap();
+
+    PreparedGroth16Verifier::from_prepared_inputs(
+        proof[0..64].try_into().unwrap(),
+        proof[64..192].try_into().unwrap(),
+        proof[192..256].try_into().unwrap(),
+        &acc,
+        &vk,
+    )
+    .unwrap()
+    .verify()
+    .unwrap();
+}
+
+#[test]
+fn inputs_hash_does_not_depend_on_chunks() {
+    let public_inputs = [[1u8; 32], [2u8; 32], [3u8; 32]];
+
+    let whole = chain_inputs_hash(&[0u8; 32], &public_inputs);
+    let hash = chain_inputs_hash(&[0u8; 32], &public_inputs[..1]);
+    let chunked = chain_inputs_hash(&hash, &public_inputs[1..]);
+
+    assert_eq!(whole, chunked);
+    assert_ne!(
+        whole,
+        chain_inputs_hash(&[0u8; 32], &[[2u8; 32], [1u8; 32], [3u8; 32]])
+    );
+}
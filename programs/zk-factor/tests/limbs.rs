@@ -0,0 +1,56 @@
+use num_bigint::BigUint;
+use zk_factor::{
+    from_limbs, is_canonical_modulus, pad_limbs, to_limbs, u64_to_scalar, PublicInputs, LIMB_BITS,
+    MAX_MODULUS_LIMBS,
+};
+
+/// RSA-2048 challenge number.
+const RSA_2048: &str = "25195908475657893494027183240048398571429282126204032027777137836043662020707595556264018525880784406918290641249515082189298559149176184502808489120072844992687392807287776735971418347270261896375014971824691165077613379859095700097330459748808428401797429100642458691817195118746121515172654632282216869987549182422433637259085141865462043576798423387184774447920739934236584823824281198163815010674810451660377306056201619676256133844143603833904414952634432190114657544454178424020924616515723350778707749817125772467962926386356373289912154831438167899885040445364023527381951378636564391212010397122822120720357";
+
+#[test]
+fn limbs_are_little_endian() {
+    let n = (BigUint::from(1u8) << (2 * LIMB_BITS)) + (BigUint::from(2u8) << LIMB_BITS) + 3u8;
+
+    assert_eq!(to_limbs(&n), vec![3, 2, 1]);
+    assert_eq!(from_limbs(&[3, 2, 1]), n);
+    assert_eq!(from_limbs(&[3, 2, 1, 0, 0]), n);
+    assert!(to_limbs(&BigUint::default()).is_empty());
+    assert_eq!(from_limbs(&[]), BigUint::default());
+}
+
+#[test]
+fn rsa_2048_round_trips() {
+    let n: BigUint = RSA_2048.parse().unwrap();
+    let limbs = to_limbs(&n);
+
+    assert_eq!(limbs.len(), MAX_MODULUS_LIMBS);
+    assert!(is_canonical_modulus(&limbs));
+    assert_eq!(from_limbs(&limbs), n);
+}
+
+#[test]
+fn padding_keeps_the_value() {
+    assert_eq!(pad_limbs(&[3, 2], 4), vec![3, 2, 0, 0]);
+    assert_eq!(pad_limbs(&[3, 2], 1), vec![3, 2]);
+    assert_eq!(from_limbs(&pad_limbs(&[3, 2], 4)), from_limbs(&[3, 2]));
+}
+
+#[test]
+fn canonical_modulus() {
+    assert!(is_canonical_modulus(&[2]));
+    assert!(is_canonical_modulus(&[0, 1]));
+    assert!(!is_canonical_modulus(&[]));
+    assert!(!is_canonical_modulus(&[0]));
+    assert!(!is_canonical_modulus(&[1]));
+    assert!(!is_canonical_modulus(&[2, 0]));
+}
+
+#[test]
+fn limbs_are_one_public_input_each() {
+    let public_inputs = PublicInputs::new().limbs(&[3, u64::MAX]).u64(7).build();
+
+    assert_eq!(
+        public_inputs,
+        vec![u64_to_scalar(3), u64_to_scalar(u64::MAX), u64_to_scalar(7)]
+    );
+}
@@ -0,0 +1,33 @@
+use solana_bn254::compression::prelude::{alt_bn128_g1_compress, alt_bn128_g2_compress};
+use zk_factor::proof_nullifier;
+
+mod common;
+
+use common::raw_proof;
+
+#[test]
+fn compressed_and_uncompressed_proofs_share_nullifier() {
+    let proof = raw_proof();
+    let (proof_a, proof_b, proof_c) = (&proof[0..64], &proof[64..192], &proof[192..256]);
+
+    let compressed_a = alt_bn128_g1_compress(proof_a).unwrap();
+    let compressed_b = alt_bn128_g2_compress(proof_b).unwrap();
+    let compressed_c = alt_bn128_g1_compress(proof_c).unwrap();
+
+    assert_eq!(
+        proof_nullifier(proof_a, proof_b, proof_c),
+        proof_nullifier(&compressed_a, &compressed_b, &compressed_c)
+    );
+}
+
+#[test]
+fn different_proofs_have_different_nullifiers() {
+    let proof = raw_proof();
+    let mut other = proof;
+    other[200] ^= 1;
+
+    assert_ne!(
+        proof_nullifier(&proof[0..64], &proof[64..192], &proof[192..256]),
+        proof_nullifier(&other[0..64], &other[64..192], &other[192..256])
+    );
+}
@@ -0,0 +1,74 @@
+use zk_factor::{
+    deposit_amounts, first_deposit_lp, initial_lp, swap_out, withdraw_amounts, MINIMUM_LIQUIDITY,
+};
+
+#[test]
+fn initial_lp_is_geometric_mean() {
+    assert_eq!(initial_lp(100, 400), Some(200));
+    assert_eq!(initial_lp(2, 3), Some(2));
+    assert_eq!(initial_lp(0, 1_000), Some(0));
+    assert_eq!(initial_lp(u64::MAX, u64::MAX), Some(u64::MAX));
+}
+
+#[test]
+fn first_deposit_locks_minimum_liquidity() {
+    assert_eq!(first_deposit_lp(100_000, 400_000), Some(199_000));
+    assert_eq!(first_deposit_lp(1_000, 1_000), Some(0));
+    assert_eq!(first_deposit_lp(1, 1), None);
+}
+
+#[test]
+fn donation_cannot_round_the_next_deposit_to_nothing() {
+    // The first depositor mints the smallest pool possible and donates a
+    // large amount to the vaults.
+    let supply = initial_lp(1_001, 1_001).unwrap();
+    assert_eq!(supply, MINIMUM_LIQUIDITY + 1);
+    let (vault_x, vault_y) = (1_001 + 1_000_000_000, 1_001 + 1_000_000_000);
+
+    // A share now costs about 1_000_000 tokens instead of 1_000_000_000.
+    let (amount_x, amount_y) = deposit_amounts(1, vault_x, vault_y, supply).unwrap();
+    assert!(amount_x < 1_000_000 && amount_y < 1_000_000);
+}
+
+#[test]
+fn deposit_rounds_up_and_withdraw_rounds_down() {
+    assert_eq!(deposit_amounts(1, 10, 15, 3), Some((4, 5)));
+    assert_eq!(withdraw_amounts(1, 10, 15, 3), Some((3, 5)));
+}
+
+#[test]
+fn deposit_then_withdraw_cannot_drain_the_pool() {
+    let (vault_x, vault_y, supply) = (1_000_003, 2_000_011, 1_414_215);
+    for lp in [1, 7, 1_000, supply] {
+        let (in_x, in_y) = deposit_amounts(lp, vault_x, vault_y, supply).unwrap();
+        let (out_x, out_y) =
+            withdraw_amounts(lp, vault_x + in_x, vault_y + in_y, supply + lp).unwrap();
+        assert!(out_x <= in_x && out_y <= in_y);
+    }
+}
+
+#[test]
+fn withdraw_more_than_supply_is_rejected() {
+    assert_eq!(withdraw_amounts(11, 100, 100, 10), None);
+    assert_eq!(deposit_amounts(1, 100, 100, 0), None);
+}
+
+#[test]
+fn swap_keeps_the_product_from_decreasing() {
+    let (vault_in, vault_out) = (1_000_000u64, 5_000_000u64);
+    for (amount_in, fee_bps) in [(1, 30), (1_000, 0), (1_000, 30), (10_000_000, 100)] {
+        let out = swap_out(amount_in, vault_in, vault_out, fee_bps).unwrap();
+        assert!(out < vault_out);
+        let before = vault_in as u128 * vault_out as u128;
+        let after = (vault_in + amount_in) as u128 * (vault_out - out) as u128;
+        assert!(after >= before);
+    }
+}
+
+#[test]
+fn swap_fee_reduces_output() {
+    assert_eq!(swap_out(1_000, 1_000_000, 1_000_000, 0), Some(999));
+    assert_eq!(swap_out(1_000, 1_000_000, 1_000_000, 30), Some(996));
+    assert_eq!(swap_out(1_000, 0, 0, 30), Some(0));
+    assert_eq!(swap_out(1_000, 1_000, 1_000, 10_001), None);
+}
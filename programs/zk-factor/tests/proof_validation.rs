@@ -0,0 +1,130 @@
+use ark_bn254::{Fq, Fq2, G2Affine};
+use ark_ff::{BigInteger, PrimeField};
+use zk_factor::{check_proof, Groth16Error, Groth16Verifier, PUBLIC_INPUT, VERIFYINGKEY};
+
+mod common;
+
+use common::raw_proof;
+
+fn verify(
+    proof_a: &[u8; 64],
+    proof_b: &[u8; 128],
+    proof_c: &[u8; 64],
+) -> Result<bool, Groth16Error> {
+    Groth16Verifier::<'_, 1>::new(proof_a, proof_b, proof_c, &PUBLIC_INPUT, &VERIFYINGKEY)?
+        .prepare_inputs()?
+        .verify()
+}
+
+fn split(proof: &[u8; 256]) -> ([u8; 64], [u8; 128], [u8; 64]) {
+    (
+        proof[0..64].try_into().unwrap(),
+        proof[64..192].try_into().unwrap(),
+        proof[192..256].try_into().unwrap(),
+    )
+}
+
+/// A point of the G2 curve outside of the prime order subgroup.
+fn g2_not_in_subgroup() -> [u8; 128] {
+    let point = (1u64..)
+        .filter_map(|x| G2Affine::get_point_from_x_unchecked(Fq2::from(x), false))
+        .find(|point| !point.is_in_correct_subgroup_assuming_on_curve())
+        .unwrap();
+
+    [
+        point.x.c1.into_bigint().to_bytes_be(),
+        point.x.c0.into_bigint().to_bytes_be(),
+        point.y.c1.into_bigint().to_bytes_be(),
+        point.y.c0.into_bigint().to_bytes_be(),
+    ]
+    .concat()
+    .try_into()
+    .unwrap()
+}
+
+#[test]
+fn accepts_valid_proof() {
+    let (proof_a, proof_b, proof_c) = split(&raw_proof());
+
+    assert!(verify(&proof_a, &proof_b, &proof_c).unwrap());
+}
+
+#[test]
+fn rejects_coordinate_not_in_field() {
+    let (mut proof_a, proof_b, proof_c) = split(&raw_proof());
+    proof_a[..32].copy_from_slice(&Fq::MODULUS.to_bytes_be());
+
+    assert!(matches!(
+        verify(&proof_a, &proof_b, &proof_c),
+        Err(Groth16Error::ProofCoordinateNotInField)
+    ));
+}
+
+#[test]
+fn rejects_point_not_on_curve() {
+    let (proof_a, proof_b, mut proof_c) = split(&raw_proof());
+    proof_c[63] ^= 1;
+
+    assert!(matches!(
+        verify(&proof_a, &proof_b, &proof_c),
+        Err(Groth16Error::ProofPointNotOnCurve)
+    ));
+}
+
+/// snarkjs orders G2 coordinates `c0, c1`, the syscalls expect `c1, c0`.
+#[test]
+fn rejects_g2_with_swapped_coordinates() {
+    let (proof_a, mut proof_b, proof_c) = split(&raw_proof());
+    proof_b[..64].rotate_left(32);
+    proof_b[64..].rotate_left(32);
+
+    assert!(matches!(
+        verify(&proof_a, &proof_b, &proof_c),
+        Err(Groth16Error::ProofPointNotOnCurve)
+    ));
+}
+
+#[test]
+fn rejects_point_at_infinity() {
+    let (proof_a, proof_b, _) = split(&raw_proof());
+
+    assert!(matches!(
+        verify(&proof_a, &proof_b, &[0u8; 64]),
+        Err(Groth16Error::ProofPointAtInfinity)
+    ));
+}
+
+#[test]
+fn rejects_g2_not_in_subgroup() {
+    let (proof_a, _, proof_c) = split(&raw_proof());
+
+    assert!(matches!(
+        verify(&proof_a, &g2_not_in_subgroup(), &proof_c),
+        Err(Groth16Error::ProofPointNotInSubgroup)
+    ));
+}
+
+/// Rejected by the up front checks, before any pairing.
+#[test]
+fn check_proof_rejects_g2_not_in_subgroup() {
+    let (proof_a, proof_b, proof_c) = split(&raw_proof());
+
+    check_proof(&proof_a, &proof_b, &proof_c).unwrap();
+    assert!(matches!(
+        check_proof(&proof_a, &g2_not_in_subgroup(), &proof_c),
+        Err(Groth16Error::ProofPointNotInSubgroup)
+    ));
+}
+
+/// `A` must be negated by the client, a valid point that fails the pairing.
+#[test]
+fn rejects_proof_a_not_negated() {
+    let (mut proof_a, proof_b, proof_c) = split(&raw_proof());
+    let y = -Fq::from_be_bytes_mod_order(&proof_a[32..]);
+    proof_a[32..].copy_from_slice(&y.into_bigint().to_bytes_be());
+
+    assert!(matches!(
+        verify(&proof_a, &proof_b, &proof_c),
+        Err(Groth16Error::ProofVerificationFailed)
+    ));
+}
@@ -0,0 +1,119 @@
+use anchor_lang::{error::Error, prelude::Pubkey};
+use zk_factor::{
+    bool_to_scalar, hash_to_scalar, hash_to_scalars, pubkey_to_scalars, u128_to_scalar,
+    u64_to_scalar, Groth16Error, PublicInputs, ZkFactorError, PUBLIC_INPUT,
+};
+
+/// BN254 scalar field modulus.
+const FIELD_MODULUS: &str =
+    "21888242871839275222246405745857351013691117015963307587463839034143234899009";
+
+fn error_code(err: Error) -> u32 {
+    match err {
+        Error::AnchorError(err) => err.error_code_number,
+        Error::ProgramError(err) => panic!("unexpected program error {err}"),
+    }
+}
+
+#[test]
+fn integers_are_big_endian() {
+    let mut expected = [0u8; 32];
+    expected[30..].copy_from_slice(&[0x05, 0x39]);
+
+    assert_eq!(u64_to_scalar(1337), expected);
+    assert_eq!(u128_to_scalar(1337), expected);
+    assert_eq!(PUBLIC_INPUT, [expected]);
+    assert_eq!(u128_to_scalar(u128::MAX)[..16], [0u8; 16]);
+    assert_eq!(u128_to_scalar(u128::MAX)[16..], [0xff; 16]);
+    assert_eq!(bool_to_scalar(true), u64_to_scalar(1));
+    assert_eq!(bool_to_scalar(false), [0u8; 32]);
+}
+
+#[test]
+fn builder_matches_const_fns() {
+    let pubkey = Pubkey::new_unique();
+    let hash = [0xab; 32];
+
+    let public_inputs = PublicInputs::new()
+        .u64(7)
+        .u128(u128::MAX)
+        .bool(true)
+        .pubkey(&pubkey)
+        .hash(&hash)
+        .hash_split(&hash)
+        .decimal("1337")
+        .unwrap()
+        .build();
+
+    let [pubkey_hi, pubkey_lo] = pubkey_to_scalars(&pubkey);
+    let [hash_hi, hash_lo] = hash_to_scalars(&hash);
+    assert_eq!(
+        public_inputs,
+        vec![
+            u64_to_scalar(7),
+            u128_to_scalar(u128::MAX),
+            bool_to_scalar(true),
+            pubkey_hi,
+            pubkey_lo,
+            hash_to_scalar(&hash),
+            hash_hi,
+            hash_lo,
+            u64_to_scalar(1337),
+        ]
+    );
+}
+
+#[test]
+fn hashes_fit_in_the_field() {
+    let hash = [0xff; 32];
+
+    assert_eq!(hash_to_scalar(&hash)[0], 0);
+    assert_eq!(hash_to_scalar(&hash)[1..], hash[1..]);
+    assert!(PublicInputs::new().scalar(hash_to_scalar(&hash)).is_ok());
+    for scalar in hash_to_scalars(&hash) {
+        assert!(PublicInputs::new().scalar(scalar).is_ok());
+    }
+}
+
+#[test]
+fn pubkey_is_split_in_128_bit_limbs() {
+    let pubkey = Pubkey::new_from_array(core::array::from_fn(|i| i as u8));
+    let [hi, lo] = pubkey_to_scalars(&pubkey);
+
+    assert_eq!(hi[..16], [0u8; 16]);
+    assert_eq!(lo[..16], [0u8; 16]);
+    assert_eq!(hi[16..], pubkey.to_bytes()[..16]);
+    assert_eq!(lo[16..], pubkey.to_bytes()[16..]);
+}
+
+#[test]
+fn rejects_scalars_out_of_the_field() {
+    let err = PublicInputs::new().decimal(FIELD_MODULUS).unwrap_err();
+    assert_eq!(
+        error_code(err),
+        u32::from(Groth16Error::PublicInputGreaterThenFieldSize)
+    );
+
+    let err = PublicInputs::new().decimal(&"9".repeat(100)).unwrap_err();
+    assert_eq!(
+        error_code(err),
+        u32::from(Groth16Error::PublicInputGreaterThenFieldSize)
+    );
+
+    let err = PublicInputs::new().scalar([0xff; 32]).unwrap_err();
+    assert_eq!(
+        error_code(err),
+        u32::from(Groth16Error::PublicInputGreaterThenFieldSize)
+    );
+}
+
+#[test]
+fn rejects_invalid_decimals() {
+    for value in ["", "0x10", "-1", "1.5"] {
+        let err = PublicInputs::new().decimal(value).unwrap_err();
+        assert_eq!(
+            error_code(err),
+            u32::from(ZkFactorError::InvalidPublicInput)
+        );
+    }
+}
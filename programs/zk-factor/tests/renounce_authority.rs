@@ -0,0 +1,55 @@
+#![cfg(feature = "test-sbf")]
+
+//! Sends real instructions to the built program, run with
+//! `anchor build && cargo test --features test-sbf`.
+
+mod svm;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use litesvm::types::TransactionResult;
+use solana_sdk::{instruction::Instruction, signer::Signer};
+use svm::{assert_error, TestContext};
+use zk_factor::{accounts, instruction, ZkFactorError};
+
+fn set_locked(ctx: &mut TestContext, data: Vec<u8>) -> TransactionResult {
+    let ix = Instruction {
+        program_id: zk_factor::ID,
+        accounts: accounts::SetLockedAccounts {
+            authority: ctx.authority.pubkey(),
+            config: ctx.config,
+        }
+        .to_account_metas(None),
+        data,
+    };
+    let authority = ctx.authority.insecure_clone();
+    ctx.send(ix, &authority)
+}
+
+fn renounce(ctx: &mut TestContext) -> TransactionResult {
+    let ix = Instruction {
+        program_id: zk_factor::ID,
+        accounts: accounts::RenounceAuthorityAccounts {
+            authority: ctx.authority.pubkey(),
+            config: ctx.config,
+        }
+        .to_account_metas(None),
+        data: instruction::RenounceAuthority {}.data(),
+    };
+    let authority = ctx.authority.insecure_clone();
+    ctx.send(ix, &authority)
+}
+
+#[test]
+fn locked_config_cannot_be_renounced() {
+    let mut ctx = TestContext::new();
+
+    set_locked(&mut ctx, instruction::Lock {}.data()).unwrap();
+    assert_error(renounce(&mut ctx), ZkFactorError::PoolLocked);
+
+    set_locked(&mut ctx, instruction::Unlock {}.data()).unwrap();
+    renounce(&mut ctx).unwrap();
+    assert_error(
+        set_locked(&mut ctx, instruction::Lock {}.data()),
+        ZkFactorError::Unauthorized,
+    );
+}
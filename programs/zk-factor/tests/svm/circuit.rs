@@ -0,0 +1,112 @@
+//! `circuits/factor_bound.circom` as an arkworks circuit, so that the tests
+//! can run their own setup and prove for any signer without the circom
+//! toolchain.
+
+use anchor_lang::prelude::Pubkey;
+use ark_bn254::{Bn254, Fq, Fr, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::{Groth16, Proof, ProvingKey, VerifyingKey};
+use ark_relations::{
+    lc,
+    r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError},
+};
+use ark_snark::SNARK;
+use ark_std::rand::{CryptoRng, RngCore};
+use zk_factor::{pubkey_to_scalars, VerifyingKeyInput};
+
+/// Knowledge of `p * q = n`, bound to `prover`.
+///
+/// Public inputs: `[n, prover_hi, prover_lo]`.
+pub struct FactorBound {
+    pub p: u64,
+    pub q: u64,
+    pub prover: Pubkey,
+}
+
+impl ConstraintSynthesizer<Fr> for FactorBound {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let [hi, lo] = pubkey_to_scalars(&self.prover).map(|s| Fr::from_be_bytes_mod_order(&s));
+
+        let n = cs.new_input_variable(|| Ok(Fr::from(self.p) * Fr::from(self.q)))?;
+        let prover_hi = cs.new_input_variable(|| Ok(hi))?;
+        let prover_lo = cs.new_input_variable(|| Ok(lo))?;
+        let p = cs.new_witness_variable(|| Ok(Fr::from(self.p)))?;
+        let q = cs.new_witness_variable(|| Ok(Fr::from(self.q)))?;
+        let prover_hi_sq = cs.new_witness_variable(|| Ok(hi * hi))?;
+        let prover_lo_sq = cs.new_witness_variable(|| Ok(lo * lo))?;
+
+        cs.enforce_constraint(lc!() + p, lc!() + q, lc!() + n)?;
+        cs.enforce_constraint(lc!() + prover_hi, lc!() + prover_hi, lc!() + prover_hi_sq)?;
+        cs.enforce_constraint(lc!() + prover_lo, lc!() + prover_lo, lc!() + prover_lo_sq)?;
+
+        Ok(())
+    }
+}
+
+/// Circuit specific setup, the witness values do not matter.
+pub fn setup<R: RngCore + CryptoRng>(rng: &mut R) -> ProvingKey<Bn254> {
+    let circuit = FactorBound {
+        p: 1,
+        q: 1,
+        prover: Pubkey::default(),
+    };
+    Groth16::<Bn254>::generate_random_parameters_with_reduction(circuit, rng).unwrap()
+}
+
+/// Proof that `prover` knows `p * q`, in the syscall encoding.
+pub fn prove<R: RngCore + CryptoRng>(
+    pk: &ProvingKey<Bn254>,
+    circuit: FactorBound,
+    rng: &mut R,
+) -> ([u8; 64], [u8; 128], [u8; 64]) {
+    proof_to_be_bytes(&Groth16::<Bn254>::prove(pk, circuit, rng).unwrap())
+}
+
+/// Proof in the syscall encoding, with `A` negated as the verifier expects.
+pub fn proof_to_be_bytes(proof: &Proof<Bn254>) -> ([u8; 64], [u8; 128], [u8; 64]) {
+    (
+        g1_to_be_bytes(&(-proof.a.into_group()).into()),
+        g2_to_be_bytes(&proof.b),
+        g1_to_be_bytes(&proof.c),
+    )
+}
+
+pub fn verifying_key_input(vk: &VerifyingKey<Bn254>) -> VerifyingKeyInput {
+    VerifyingKeyInput {
+        vk_alpha_g1: g1_to_be_bytes(&vk.alpha_g1),
+        vk_beta_g2: g2_to_be_bytes(&vk.beta_g2),
+        vk_gamme_g2: g2_to_be_bytes(&vk.gamma_g2),
+        vk_delta_g2: g2_to_be_bytes(&vk.delta_g2),
+        vk_ic: vk.gamma_abc_g1.iter().map(g1_to_be_bytes).collect(),
+    }
+}
+
+/// Negates a G1 point in the syscall encoding, e.g. to send `A` as is.
+pub fn negate_g1(point: &[u8; 64]) -> [u8; 64] {
+    let x = Fq::from_be_bytes_mod_order(&point[..32]);
+    let y = Fq::from_be_bytes_mod_order(&point[32..]);
+    g1_to_be_bytes(&G1Affine::new_unchecked(x, -y))
+}
+
+pub fn g1_to_be_bytes(point: &G1Affine) -> [u8; 64] {
+    [
+        point.x.into_bigint().to_bytes_be(),
+        point.y.into_bigint().to_bytes_be(),
+    ]
+    .concat()
+    .try_into()
+    .unwrap()
+}
+
+pub fn g2_to_be_bytes(point: &G2Affine) -> [u8; 128] {
+    [
+        point.x.c1.into_bigint().to_bytes_be(),
+        point.x.c0.into_bigint().to_bytes_be(),
+        point.y.c1.into_bigint().to_bytes_be(),
+        point.y.c0.into_bigint().to_bytes_be(),
+    ]
+    .concat()
+    .try_into()
+    .unwrap()
+}
@@ -0,0 +1,246 @@
+//! In-process harness loading the `zk_factor` program built by
+//! `anchor build` into LiteSVM.
+//!
+//! Shared by the program tests, each using part of it.
+#![allow(dead_code)]
+
+pub mod circuit;
+
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::{program_option::COption, program_pack::Pack},
+    InstructionData, ToAccountMetas,
+};
+use anchor_spl::token::spl_token;
+use ark_bn254::Bn254;
+use ark_groth16::ProvingKey;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use litesvm::{types::TransactionResult, LiteSVM};
+use solana_sdk::{
+    account::Account,
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{Instruction, InstructionError},
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use zk_factor::{
+    accounts, instruction, InitializeInput, CIRCUIT_SEED, CONFIG_SEED, FACTOR_BOUND_CIRCUIT_ID,
+    LP_SEED, NULLIFIER_SEED, TREASURY_SEED,
+};
+
+use circuit::{prove, setup, verifying_key_input, FactorBound};
+
+const PROGRAM_PATH: &str = "../../target/deploy/zk_factor.so";
+const CONFIG_SEED_VALUE: u64 = 1;
+
+pub struct TestContext {
+    pub svm: LiteSVM,
+    pub authority: Keypair,
+    pub config: Pubkey,
+    pub treasury: Pubkey,
+    pub circuit: Pubkey,
+    pub proving_key: ProvingKey<Bn254>,
+    pub rng: StdRng,
+}
+
+impl TestContext {
+    /// Deploys the program, creates a config without fee and registers the
+    /// `factor_bound` circuit with a fresh setup.
+    pub fn new() -> TestContext {
+        let mut svm = LiteSVM::new();
+        svm.add_program_from_file(zk_factor::ID, PROGRAM_PATH)
+            .expect("build the program with `anchor build` first");
+
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(1337);
+        let proving_key = setup(&mut rng);
+
+        let config = Pubkey::find_program_address(
+            &[CONFIG_SEED, CONFIG_SEED_VALUE.to_le_bytes().as_ref()],
+            &zk_factor::ID,
+        )
+        .0;
+        let treasury =
+            Pubkey::find_program_address(&[TREASURY_SEED, config.as_ref()], &zk_factor::ID).0;
+        let circuit = Pubkey::find_program_address(
+            &[
+                CIRCUIT_SEED,
+                config.as_ref(),
+                FACTOR_BOUND_CIRCUIT_ID.as_ref(),
+            ],
+            &zk_factor::ID,
+        )
+        .0;
+
+        let mut ctx = TestContext {
+            svm,
+            authority,
+            config,
+            treasury,
+            circuit,
+            proving_key,
+            rng,
+        };
+        ctx.create_config();
+        ctx.register_circuit();
+        ctx
+    }
+
+    pub fn new_user(&mut self) -> Keypair {
+        let user = Keypair::new();
+        self.svm.airdrop(&user.pubkey(), 1_000_000_000).unwrap();
+        user
+    }
+
+    /// Proof that `prover` knows `p * q`, see [`FactorBound`].
+    pub fn prove(&mut self, p: u64, q: u64, prover: &Pubkey) -> InitializeInput {
+        let circuit = FactorBound {
+            p,
+            q,
+            prover: *prover,
+        };
+        let (proof_a, proof_b, proof_c) = prove(&self.proving_key, circuit, &mut self.rng);
+
+        InitializeInput {
+            proof_a,
+            proof_b,
+            proof_c,
+        }
+    }
+
+    pub fn initialize(&mut self, user: &Keypair, input: InitializeInput) -> TransactionResult {
+        let nullifier = Pubkey::find_program_address(
+            &[
+                NULLIFIER_SEED,
+                self.config.as_ref(),
+                input.nullifier().as_ref(),
+            ],
+            &zk_factor::ID,
+        )
+        .0;
+
+        let ix = Instruction {
+            program_id: zk_factor::ID,
+            accounts: accounts::InitializeAccounts {
+                user: user.pubkey(),
+                config: self.config,
+                treasury: self.treasury,
+                circuit: self.circuit,
+                nullifier,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::Initialize { input }.data(),
+        };
+        self.send(ix, user)
+    }
+
+    pub fn send(&mut self, ix: Instruction, payer: &Keypair) -> TransactionResult {
+        self.send_all(&[ix], payer)
+    }
+
+    pub fn send_all(&mut self, ixs: &[Instruction], payer: &Keypair) -> TransactionResult {
+        // Sending the same instruction twice must not be deduplicated.
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            ixs,
+            Some(&payer.pubkey()),
+            &[payer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm.send_transaction(tx)
+    }
+
+    fn create_config(&mut self) {
+        let mint_x = self.create_mint();
+        let mint_y = self.create_mint();
+        let mint_lp =
+            Pubkey::find_program_address(&[LP_SEED, self.config.as_ref()], &zk_factor::ID).0;
+
+        let ix = Instruction {
+            program_id: zk_factor::ID,
+            accounts: accounts::CreateConfigAccounts {
+                authority: self.authority.pubkey(),
+                config: self.config,
+                mint_x,
+                mint_y,
+                mint_lp,
+                treasury: self.treasury,
+                token_program: spl_token::ID,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::CreateConfig {
+                seed: CONFIG_SEED_VALUE,
+                fee: 0,
+                swap_fee_bps: 0,
+                verification_fee: 0,
+            }
+            .data(),
+        };
+        let authority = self.authority.insecure_clone();
+        self.send(ix, &authority).unwrap();
+    }
+
+    fn register_circuit(&mut self) {
+        let ix = Instruction {
+            program_id: zk_factor::ID,
+            accounts: accounts::RegisterCircuitAccounts {
+                authority: self.authority.pubkey(),
+                config: self.config,
+                circuit: self.circuit,
+                system_program: anchor_lang::system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::RegisterCircuit {
+                circuit_id: FACTOR_BOUND_CIRCUIT_ID,
+                input: verifying_key_input(&self.proving_key.vk),
+            }
+            .data(),
+        };
+        // The verifying key is validated on registration.
+        let budget = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let authority = self.authority.insecure_clone();
+        self.send_all(&[budget, ix], &authority).unwrap();
+    }
+
+    fn create_mint(&mut self) -> Pubkey {
+        let mint = Pubkey::new_unique();
+        let mut data = vec![0u8; spl_token::state::Mint::LEN];
+        spl_token::state::Mint {
+            mint_authority: COption::Some(self.authority.pubkey()),
+            supply: 0,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+
+        self.svm
+            .set_account(
+                mint,
+                Account {
+                    lamports: self.svm.minimum_balance_for_rent_exemption(data.len()),
+                    data,
+                    owner: spl_token::ID,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .unwrap();
+        mint
+    }
+}
+
+/// Asserts that the transaction failed with the anchor error `code` in its
+/// only instruction.
+pub fn assert_error(result: TransactionResult, code: impl Into<u32>) {
+    let err = result.expect_err("transaction should fail").err;
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::Custom(code.into()))
+    );
+}
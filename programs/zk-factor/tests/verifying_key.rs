@@ -0,0 +1,151 @@
+// Shared with the program tests, which use all of it.
+#[allow(dead_code)]
+#[path = "svm/circuit.rs"]
+mod circuit;
+
+use anchor_lang::error::Error;
+use ark_bn254::{Fq2, G2Affine};
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use circuit::{setup, verifying_key_input};
+use zk_factor::{Circuit, Groth16Error, Groth16Verifyingkey, VerifyingKeyInput, VERIFYINGKEY};
+
+fn g2_to_be_bytes(point: &G2Affine) -> [u8; 128] {
+    [
+        point.x.c1.into_bigint().to_bytes_be(),
+        point.x.c0.into_bigint().to_bytes_be(),
+        point.y.c1.into_bigint().to_bytes_be(),
+        point.y.c0.into_bigint().to_bytes_be(),
+    ]
+    .concat()
+    .try_into()
+    .unwrap()
+}
+
+#[test]
+fn setup_verifying_key_is_valid() {
+    let pk = setup(&mut StdRng::seed_from_u64(0));
+    let input = verifying_key_input(&pk.vk);
+    let vk = Groth16Verifyingkey {
+        nr_pubinputs: input.vk_ic.len() - 1,
+        vk_alpha_g1: input.vk_alpha_g1,
+        vk_beta_g2: input.vk_beta_g2,
+        vk_gamme_g2: input.vk_gamme_g2,
+        vk_delta_g2: input.vk_delta_g2,
+        vk_ic: &input.vk_ic,
+    };
+
+    assert!(vk.validate().is_ok());
+}
+
+/// The setup of the `factor` key skipped the phase 2 contribution, which
+/// leaves gamma equal to delta. This is why it is only compiled with the
+/// `dev-vk` feature, for the fixtures.
+#[test]
+fn factor_dev_key_gamma_equals_delta() {
+    assert!(matches!(
+        VERIFYINGKEY.validate(),
+        Err(Groth16Error::VerifyingKeyGammaEqualsDelta)
+    ));
+}
+
+#[test]
+fn circuit_registration_validates_the_key() {
+    let mut circuit: Circuit = bytemuck::Zeroable::zeroed();
+    let dev_key = VerifyingKeyInput {
+        vk_alpha_g1: VERIFYINGKEY.vk_alpha_g1,
+        vk_beta_g2: VERIFYINGKEY.vk_beta_g2,
+        vk_gamme_g2: VERIFYINGKEY.vk_gamme_g2,
+        vk_delta_g2: VERIFYINGKEY.vk_delta_g2,
+        vk_ic: VERIFYINGKEY.vk_ic.to_vec(),
+    };
+
+    match circuit.set_verifying_key(&dev_key) {
+        Err(Error::AnchorError(err)) => assert_eq!(
+            err.error_code_number,
+            u32::from(Groth16Error::VerifyingKeyGammaEqualsDelta)
+        ),
+        result => panic!("unexpected {result:?}"),
+    }
+
+    let pk = setup(&mut StdRng::seed_from_u64(0));
+    circuit
+        .set_verifying_key(&verifying_key_input(&pk.vk))
+        .unwrap();
+    assert_eq!(circuit.nr_pubinputs, 3);
+}
+
+#[test]
+fn rejects_wrong_nr_pubinputs() {
+    let vk = Groth16Verifyingkey {
+        nr_pubinputs: 2,
+        ..VERIFYINGKEY
+    };
+
+    assert!(matches!(
+        vk.validate(),
+        Err(Groth16Error::VerifyingKeyInvalidNrPublicInputs)
+    ));
+}
+
+#[test]
+fn rejects_coordinate_not_in_field() {
+    let mut vk_alpha_g1 = VERIFYINGKEY.vk_alpha_g1;
+    vk_alpha_g1[..32].copy_from_slice(&[0xff; 32]);
+    let vk = Groth16Verifyingkey {
+        vk_alpha_g1,
+        ..VERIFYINGKEY
+    };
+
+    assert!(matches!(
+        vk.validate(),
+        Err(Groth16Error::VerifyingKeyCoordinateNotInField)
+    ));
+}
+
+#[test]
+fn rejects_point_not_on_curve() {
+    let mut vk_ic = VERIFYINGKEY.vk_ic.to_vec();
+    vk_ic[1][63] ^= 1;
+    let vk = Groth16Verifyingkey {
+        vk_ic: &vk_ic,
+        ..VERIFYINGKEY
+    };
+
+    assert!(matches!(
+        vk.validate(),
+        Err(Groth16Error::VerifyingKeyPointNotOnCurve)
+    ));
+}
+
+#[test]
+fn rejects_point_at_infinity() {
+    let vk = Groth16Verifyingkey {
+        vk_beta_g2: [0u8; 128],
+        ..VERIFYINGKEY
+    };
+
+    assert!(matches!(
+        vk.validate(),
+        Err(Groth16Error::VerifyingKeyPointAtInfinity)
+    ));
+}
+
+#[test]
+fn rejects_g2_point_not_in_subgroup() {
+    // Points of the twist outside of the prime order subgroup are easy to find
+    // since the G2 cofactor is not 1.
+    let point = (1u64..)
+        .filter_map(|x| G2Affine::get_point_from_x_unchecked(Fq2::new(x.into(), 1u64.into()), true))
+        .find(|point| !point.is_in_correct_subgroup_assuming_on_curve())
+        .unwrap();
+    let vk = Groth16Verifyingkey {
+        vk_delta_g2: g2_to_be_bytes(&point),
+        ..VERIFYINGKEY
+    };
+
+    assert!(matches!(
+        vk.validate(),
+        Err(Groth16Error::VerifyingKeyPointNotInSubgroup)
+    ));
+}
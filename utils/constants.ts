@@ -1 +1,4 @@
 export const LAMPORTS_PER_SOL = 1_000_000_000n;
+
+// Seed of the zk-factor config used by `main.ts`.
+export const CONFIG_SEED = 1n;
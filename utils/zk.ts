@@ -1,3 +1,4 @@
+import { createHash } from "node:crypto";
 import type { Groth16Proof } from "snarkjs";
 import { bigIntToLeUint8Array } from "./helpers.ts";
 // @ts-ignore - ffjavascript is not typed
@@ -40,6 +41,31 @@ export const proofToUint8Array = (proof: Groth16Proof) => {
   return { proofA, proofB, proofC };
 };
 
+// Seed of the nullifier PDA of a proof, as `proof_nullifier` computes it on
+// chain from the x coordinates of its points, without the two flag bits of
+// compressed points.
+export function proofNullifier(
+  proofA: Uint8Array,
+  proofB: Uint8Array,
+  proofC: Uint8Array,
+) {
+  const aX = proofA.slice(0, 32);
+  const bX = proofB.slice(0, 64);
+  const cX = proofC.slice(0, 32);
+  aX[0] &= 0x3f;
+  bX[0] &= 0x3f;
+  cX[0] &= 0x3f;
+
+  return new Uint8Array(
+    createHash("sha256")
+      .update("zk-factor:nullifier:v1")
+      .update(aX)
+      .update(bX)
+      .update(cX)
+      .digest(),
+  );
+}
+
 // biome-ignore lint/suspicious/noExplicitAny: <explanation>
 export function g1Uncompressed(curve: any, p1Raw: any) {
   const p1 = curve.G1.fromObject(p1Raw);
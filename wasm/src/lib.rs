@@ -4,7 +4,7 @@ use ark_bn254::{Fq, G1Affine};
 use ark_ff::{BigInt, PrimeField};
 use ark_serialize::CanonicalSerialize;
 use num_bigint::BigUint;
-use proofs::{PreparedProof, RawProof};
+use proofs::{CompressedProof, PreparedProof, RawProof};
 use wasm_bindgen::prelude::*;
 
 mod proofs;
@@ -79,24 +79,83 @@ pub fn prepare_proofs(raw_proof: JsValue) -> Result<JsValue, JsValue> {
     prepared_proof.try_into()
 }
 
+/// Compress a prepared proof (the `raw` bytes returned by `prepare_proofs`)
+/// into the format expected by the `initialize_compressed` instruction.
+///
+/// G1 points (proof_a, proof_c) go from 64 to 32 bytes and the G2 point
+/// (proof_b) from 128 to 64 bytes, the whole proof shrinks from 256 to 128 bytes.
+#[wasm_bindgen]
+pub fn compress_proof(raw_proof: &[u8]) -> Result<JsValue, JsValue> {
+    console_error_panic_hook::set_once();
+
+    CompressedProof::from_raw(raw_proof)?.try_into()
+}
+
+/// Encode the signer pubkey as the `prover_hi` and `prover_lo` inputs of the
+/// `factor_bound` circuit, as decimal strings ready for snarkjs.
+#[wasm_bindgen]
+pub fn pubkey_to_public_inputs(pubkey: &[u8]) -> Result<JsValue, JsValue> {
+    let pubkey: &[u8; 32] = pubkey
+        .try_into()
+        .map_err(|_| JsValue::from_str("Pubkey must be 32 bytes"))?;
+
+    let [hi, lo] = utils::pubkey_to_scalars(pubkey);
+    let inputs = utils::PubkeyInputs {
+        prover_hi: BigUint::from_bytes_be(&hi).to_string(),
+        prover_lo: BigUint::from_bytes_be(&lo).to_string(),
+    };
+
+    serde_wasm_bindgen::to_value(&inputs).map_err(|_| JsValue::null())
+}
+
+/// Split a decimal integer into `nr_limbs` 64-bit limbs, least significant
+/// first, as decimal strings ready for snarkjs, e.g. the `n`, `p` and `q`
+/// inputs of the `factor_limbs` circuit.
+#[wasm_bindgen]
+pub fn split_limbs(value: &str, nr_limbs: usize) -> Result<JsValue, JsValue> {
+    let value = BigUint::from_str(value)
+        .map_err(|_| JsValue::from_str("Value must be a decimal integer"))?;
+    let limbs: Vec<String> = utils::to_limbs(&value, nr_limbs)
+        .ok_or_else(|| JsValue::from_str("Value does not fit in the limbs"))?
+        .iter()
+        .map(|limb| limb.to_string())
+        .collect();
+
+    serde_wasm_bindgen::to_value(&limbs).map_err(|_| JsValue::null())
+}
+
+/// Recombine the decimal limbs of [`split_limbs`] into a decimal integer.
+#[wasm_bindgen]
+pub fn join_limbs(limbs: JsValue) -> Result<String, JsValue> {
+    let limbs: Vec<String> = serde_wasm_bindgen::from_value(limbs).map_err(|_| JsValue::null())?;
+    let limbs = limbs
+        .iter()
+        .map(|limb| u64::from_str(limb))
+        .collect::<Result<Vec<u64>, _>>()
+        .map_err(|_| JsValue::from_str("Limbs must be 64-bit decimal integers"))?;
+
+    Ok(utils::from_limbs(&limbs).to_string())
+}
+
 #[cfg(test)]
 mod tests {
-    use zk_factor::{Groth16Verifier, PUBLIC_INPUT, VERIFYINGKEY};
+    use crate::proofs::CompressedProof;
+    use zk_factor::{Groth16BatchVerifier, Groth16Verifier, PUBLIC_INPUT, VERIFYINGKEY};
 
     const PROOF: [u8; 256] = [
-        13, 182, 181, 4, 152, 215, 43, 245, 159, 20, 233, 21, 128, 249, 123, 6, 154, 203, 131, 37,
-        28, 246, 82, 202, 26, 78, 41, 2, 123, 203, 8, 52, 33, 94, 84, 200, 172, 138, 44, 104, 79,
-        218, 12, 82, 147, 30, 66, 186, 194, 46, 147, 44, 199, 176, 115, 51, 60, 214, 106, 59, 60,
-        26, 6, 153, 25, 247, 151, 36, 61, 112, 225, 87, 150, 209, 32, 206, 173, 252, 237, 147, 61,
-        221, 42, 156, 164, 122, 167, 69, 90, 165, 172, 77, 178, 119, 25, 123, 31, 110, 120, 167,
-        144, 55, 212, 153, 108, 240, 83, 71, 250, 205, 4, 32, 143, 49, 35, 210, 118, 127, 47, 21,
-        99, 197, 53, 228, 42, 215, 63, 51, 30, 168, 71, 154, 173, 15, 197, 54, 249, 174, 38, 51,
-        45, 119, 88, 105, 117, 221, 68, 47, 210, 90, 43, 108, 174, 172, 139, 22, 192, 112, 220,
-        170, 41, 167, 38, 58, 17, 188, 7, 182, 76, 170, 224, 133, 136, 59, 116, 66, 230, 32, 24,
-        239, 136, 45, 218, 11, 235, 6, 75, 112, 79, 105, 250, 80, 22, 86, 186, 4, 145, 57, 152,
-        186, 231, 118, 51, 150, 81, 245, 101, 127, 218, 110, 189, 246, 230, 198, 203, 110, 110,
-        186, 26, 73, 67, 75, 6, 112, 27, 114, 22, 234, 174, 26, 68, 81, 125, 212, 103, 159, 70, 15,
-        170, 221, 78, 88, 68, 176, 148, 207, 7, 49, 116, 58, 96, 193, 1, 137, 182, 135,
+        13, 218, 92, 253, 100, 202, 92, 78, 36, 41, 79, 107, 233, 175, 159, 25, 70, 130, 123, 73,
+        216, 53, 181, 55, 179, 201, 248, 77, 54, 18, 27, 40, 20, 83, 28, 173, 199, 30, 106, 17, 14,
+        3, 228, 79, 189, 66, 122, 19, 73, 123, 183, 142, 152, 218, 194, 203, 160, 68, 148, 108, 92,
+        8, 233, 30, 44, 85, 137, 191, 29, 46, 208, 98, 21, 131, 179, 210, 125, 215, 163, 140, 247,
+        97, 119, 163, 41, 1, 244, 49, 156, 224, 150, 175, 234, 40, 53, 73, 12, 124, 184, 251, 142,
+        59, 30, 176, 176, 81, 38, 124, 168, 8, 135, 201, 34, 74, 247, 170, 11, 96, 20, 108, 226,
+        13, 134, 253, 37, 47, 152, 142, 37, 90, 101, 70, 212, 231, 20, 35, 146, 206, 169, 122, 21,
+        60, 13, 108, 150, 30, 155, 120, 10, 181, 192, 64, 167, 149, 156, 221, 30, 94, 65, 59, 1,
+        218, 199, 168, 22, 221, 169, 249, 204, 28, 241, 131, 6, 248, 33, 200, 189, 148, 233, 201,
+        77, 49, 209, 135, 135, 31, 216, 102, 178, 10, 248, 3, 44, 215, 44, 82, 107, 251, 36, 223,
+        34, 115, 120, 185, 78, 179, 75, 8, 102, 146, 170, 116, 59, 184, 54, 8, 70, 1, 10, 91, 48,
+        159, 249, 96, 40, 148, 149, 136, 27, 175, 23, 21, 12, 224, 183, 97, 72, 61, 101, 132, 163,
+        19, 238, 117, 238, 13, 33, 150, 125, 164, 91, 50, 109, 173, 70, 20,
     ];
 
     #[test]
@@ -108,10 +167,88 @@ mod tests {
         let proof_b = PROOF[64..192].try_into().unwrap();
         let proof_c = PROOF[192..256].try_into().unwrap();
 
-        let mut verifier =
+        let verifier =
             Groth16Verifier::<'_, 1>::new(&proof_a, &proof_b, &proof_c, &pi, &vk).unwrap();
 
-        verifier.prepare_inputs::<true>().unwrap();
+        verifier.prepare_inputs().unwrap().verify().unwrap();
+    }
+
+    #[test]
+    fn batch_proof_should_succeed() {
+        let vk = VERIFYINGKEY;
+        let pi = PUBLIC_INPUT;
+
+        let proof_a = PROOF[0..64].try_into().unwrap();
+        let proof_b = PROOF[64..192].try_into().unwrap();
+        let proof_c = PROOF[192..256].try_into().unwrap();
+
+        let mut verifier: Groth16BatchVerifier = Groth16BatchVerifier::new(&vk);
+        verifier.push(&proof_a, &proof_b, &proof_c, &pi).unwrap();
+        verifier.push(&proof_a, &proof_b, &proof_c, &pi).unwrap();
+
         verifier.verify().unwrap();
     }
+
+    #[test]
+    fn batch_with_tampered_proof_should_fail() {
+        let vk = VERIFYINGKEY;
+        let pi = PUBLIC_INPUT;
+
+        let proof_a = PROOF[0..64].try_into().unwrap();
+        let proof_b = PROOF[64..192].try_into().unwrap();
+        let proof_c = PROOF[192..256].try_into().unwrap();
+        let proof_c_tampered = PROOF[0..64].try_into().unwrap();
+
+        let mut verifier: Groth16BatchVerifier = Groth16BatchVerifier::new(&vk);
+        verifier.push(&proof_a, &proof_b, &proof_c, &pi).unwrap();
+        verifier
+            .push(&proof_a, &proof_b, &proof_c_tampered, &pi)
+            .unwrap();
+
+        assert!(verifier.verify().is_err());
+    }
+
+    #[test]
+    fn compressed_proof_should_succeed() {
+        let vk = VERIFYINGKEY;
+        let pi = PUBLIC_INPUT;
+
+        let compressed = CompressedProof::from_raw(&PROOF).unwrap();
+        assert_eq!(compressed.raw.len(), 128);
+
+        let proof_a = compressed.proof_a.as_slice().try_into().unwrap();
+        let proof_b = compressed.proof_b.as_slice().try_into().unwrap();
+        let proof_c = compressed.proof_c.as_slice().try_into().unwrap();
+
+        let verifier =
+            Groth16Verifier::<'_, 1>::new_compressed(&proof_a, &proof_b, &proof_c, &pi, &vk)
+                .unwrap();
+
+        verifier.prepare_inputs().unwrap().verify().unwrap();
+    }
+
+    #[test]
+    fn pubkey_scalars_match_program() {
+        let pubkey = zk_factor::ID;
+
+        assert_eq!(
+            crate::utils::pubkey_to_scalars(&pubkey.to_bytes()),
+            zk_factor::pubkey_to_scalars(&pubkey)
+        );
+    }
+
+    #[test]
+    fn limbs_match_program() {
+        let n = num_bigint::BigUint::from(u64::MAX) * 3u8 + 42u8;
+        let limbs = crate::utils::to_limbs(&n, 4).unwrap();
+
+        assert_eq!(limbs[..2], zk_factor::to_limbs(&n)[..]);
+        assert_eq!(limbs[2..], [0, 0]);
+        assert_eq!(
+            crate::utils::from_limbs(&limbs),
+            zk_factor::from_limbs(&limbs)
+        );
+        assert_eq!(crate::utils::from_limbs(&limbs), n);
+        assert_eq!(crate::utils::to_limbs(&n, 1), None);
+    }
 }
@@ -2,6 +2,7 @@ use ark_bn254::{g1::G1Affine, Fq2, G2Affine};
 use ark_ff::{BigInteger, PrimeField};
 use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
+use solana_bn254::compression::prelude::{alt_bn128_g1_compress, alt_bn128_g2_compress};
 use std::ops::Neg;
 use wasm_bindgen::JsValue;
 
@@ -98,6 +99,46 @@ impl PreparedProof {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct CompressedProof {
+    pub proof_a: Vec<u8>,
+    pub proof_b: Vec<u8>,
+    pub proof_c: Vec<u8>,
+    pub raw: Vec<u8>,
+}
+
+impl CompressedProof {
+    /// Compresses a prepared (big-endian, negated A) 256 bytes proof into 128
+    /// bytes: 32 bytes for A, 64 bytes for B and 32 bytes for C.
+    pub fn from_raw(raw: &[u8]) -> Result<Self, JsValue> {
+        if raw.len() != 256 {
+            return Err(JsValue::from_str("Proof must be 256 bytes long"));
+        }
+
+        let proof_a = alt_bn128_g1_compress(&raw[0..64])
+            .map_err(|_| JsValue::from_str("Failed to compress proof_a"))?;
+        let proof_b = alt_bn128_g2_compress(&raw[64..192])
+            .map_err(|_| JsValue::from_str("Failed to compress proof_b"))?;
+        let proof_c = alt_bn128_g1_compress(&raw[192..256])
+            .map_err(|_| JsValue::from_str("Failed to compress proof_c"))?;
+
+        Ok(Self {
+            proof_a: proof_a.to_vec(),
+            proof_b: proof_b.to_vec(),
+            proof_c: proof_c.to_vec(),
+            raw: [proof_a.as_slice(), proof_b.as_slice(), proof_c.as_slice()].concat(),
+        })
+    }
+}
+
+impl TryInto<JsValue> for CompressedProof {
+    type Error = JsValue;
+
+    fn try_into(self) -> Result<JsValue, Self::Error> {
+        serde_wasm_bindgen::to_value(&self).map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+}
+
 impl TryInto<JsValue> for PreparedProof {
     type Error = JsValue;
 
@@ -1,6 +1,46 @@
+use num_bigint::BigUint;
+use serde::Serialize;
+
 pub fn convert_endianness_vec(bytes: &[u8], chunk_size: usize) -> Vec<u8> {
     bytes
         .chunks_exact(chunk_size)
         .flat_map(|chunk| chunk.iter().rev().copied())
         .collect()
 }
+
+/// Splits a pubkey into two BN254 scalars, its high and low 128 bits, as
+/// big-endian bytes. Matches `pubkey_to_scalars` in the program.
+pub fn pubkey_to_scalars(pubkey: &[u8; 32]) -> [[u8; 32]; 2] {
+    let mut hi = [0u8; 32];
+    let mut lo = [0u8; 32];
+    hi[16..].copy_from_slice(&pubkey[..16]);
+    lo[16..].copy_from_slice(&pubkey[16..]);
+    [hi, lo]
+}
+
+/// `prover_hi` and `prover_lo` circuit inputs, as decimal strings.
+#[derive(Serialize)]
+pub struct PubkeyInputs {
+    pub prover_hi: String,
+    pub prover_lo: String,
+}
+
+/// Splits `value` into `nr_limbs` little-endian 64-bit limbs, zero padded.
+/// Matches `to_limbs` in the program, up to the padding. `None` when `value`
+/// does not fit.
+pub fn to_limbs(value: &BigUint, nr_limbs: usize) -> Option<Vec<u64>> {
+    let mut limbs = value.to_u64_digits();
+    if limbs.len() > nr_limbs {
+        return None;
+    }
+    limbs.resize(nr_limbs, 0);
+    Some(limbs)
+}
+
+/// Recombines little-endian 64-bit limbs. Matches `from_limbs` in the
+/// program.
+pub fn from_limbs(limbs: &[u64]) -> BigUint {
+    limbs.iter().rev().fold(BigUint::default(), |acc, limb| {
+        (acc << 64u32) | BigUint::from(*limb)
+    })
+}